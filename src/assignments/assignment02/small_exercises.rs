@@ -1,6 +1,8 @@
 //! Small problems.
 
 use itertools::Itertools;
+use num_traits::{CheckedAdd, CheckedMul, PrimInt, Zero};
+use std::ops::Add;
 
 const FAHRENHEIT_OFFSET: f64 = 32.0;
 const FAHRENHEIT_SCALE: f64 = 5.0 / 9.0;
@@ -15,33 +17,88 @@ pub fn capitalize(input: String) -> String {
     input.to_ascii_uppercase()
 }
 
-/// Returns the sum of the given array. (We assume the absence of integer overflow.)
-pub fn sum_array(input: &[u64]) -> u64 {
-    input.iter().sum()
+/// Returns the sum of the given array. Generic over anything with a zero and addition, so this
+/// works for `u32`, `i64`, `u128`, `BigUint`, etc., not just `u64`. (We assume the absence of
+/// overflow; see [`checked_sum_array`] for a variant that detects it instead.)
+pub fn sum_array<T: Zero + Add<Output = T> + Copy>(input: &[T]) -> T {
+    input.iter().fold(T::zero(), |acc, &x| acc + x)
+}
+
+/// Concrete `u64` entry point for [`sum_array`], kept for backward compatibility with callers
+/// written before `sum_array` was generalized.
+pub fn sum_array_u64(input: &[u64]) -> u64 {
+    sum_array(input)
+}
+
+/// Sums the array like [`sum_array`], but returns `None` on overflow instead of silently
+/// wrapping, turning the "we assume no overflow" caveat into an enforceable contract.
+pub fn checked_sum_array<T: Zero + CheckedAdd + Copy>(input: &[T]) -> Option<T> {
+    input
+        .iter()
+        .try_fold(T::zero(), |acc, &x| acc.checked_add(&x))
 }
 
 /// Given a non-negative integer, say `n`, return the smallest integer of the form `3^m` that's greater than or equal to `n`.
 ///
-/// For instance, up3(6) = 9, up3(9) = 9, up3(10) = 27. (We assume the absence of integer overflow.)
-pub fn up3(n: u64) -> u64 {
-    (0..)
-        .map(|i| u64::pow(3, i))
-        .find(|&value| value >= n)
-        .unwrap_or(0)
+/// For instance, up3(6) = 9, up3(9) = 9, up3(10) = 27. Generic over any `num_traits::PrimInt`, so
+/// this works for `u32`, `i64`, `u128`, etc. (We assume the absence of overflow; see
+/// [`checked_up3`] for a variant that detects it instead.)
+pub fn up3<T: PrimInt>(n: T) -> T {
+    let three = T::from(3).expect("3 must be representable in T");
+    let mut value = T::one();
+    while value < n {
+        value = value * three;
+    }
+    value
+}
+
+/// Concrete `u64` entry point for [`up3`], kept for backward compatibility with callers written
+/// before `up3` was generalized.
+pub fn up3_u64(n: u64) -> u64 {
+    up3(n)
 }
 
-/// Returns the greatest common divisor (GCD) of two non-negative integers. (We assume the absence of integer overflow.)
-pub fn gcd(lhs: u64, rhs: u64) -> u64 {
-    let (mut m, mut n) = (lhs, rhs);
-    while m != 0 {
+/// Finds the smallest `3^m >= n` like [`up3`], but returns `None` if `3^m` would overflow `T`
+/// instead of silently wrapping.
+pub fn checked_up3<T: PrimInt + CheckedMul>(n: T) -> Option<T> {
+    let three = T::from(3)?;
+    let mut value = T::one();
+    while value < n {
+        value = value.checked_mul(&three)?;
+    }
+    Some(value)
+}
+
+/// Returns the non-negative magnitude of `value`. Unsigned `T` is always non-negative already, so
+/// this is a no-op for unsigned `T` and only actually flips the sign for negative signed `T`.
+fn magnitude<T: PrimInt>(value: T) -> T {
+    if value < T::zero() {
+        T::zero() - value
+    } else {
+        value
+    }
+}
+
+/// Returns the greatest common divisor (GCD) of two integers, i.e. the GCD of their magnitudes.
+/// Generic over any `num_traits::PrimInt`, so this works for `u32`, `i64`, `u128`, etc. (We assume
+/// the absence of integer overflow.)
+pub fn gcd<T: PrimInt>(lhs: T, rhs: T) -> T {
+    let (mut m, mut n) = (magnitude(lhs), magnitude(rhs));
+    while m != T::zero() {
         if m < n {
             std::mem::swap(&mut m, &mut n);
         }
-        m %= n;
+        m = m % n;
     }
     n
 }
 
+/// Concrete `u64` entry point for [`gcd`], kept for backward compatibility with callers written
+/// before `gcd` was generalized.
+pub fn gcd_u64(lhs: u64, rhs: u64) -> u64 {
+    gcd(lhs, rhs)
+}
+
 /// Returns the array of nC0, nC1, nC2, ..., nCn, where nCk = n! / (k! * (n-k)!). (We assume the absence of integer overflow.)
 ///
 /// Consult <https://en.wikipedia.org/wiki/Pascal%27s_triangle> for computation of binomial coefficients without integer overflow.
@@ -63,3 +120,50 @@ pub fn chooses(n: u64) -> Vec<u64> {
 pub fn zip(lhs: Vec<u64>, rhs: Vec<u64>) -> Vec<(u64, u64)> {
     lhs.into_iter().zip(rhs).collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sum_array_over_u32_and_a_wide_type() {
+        assert_eq!(sum_array(&[1u32, 2, 3, 4]), 10);
+        assert_eq!(sum_array(&[1u128, 2, 3, 4]), 10);
+        assert_eq!(sum_array_u64(&[1, 2, 3, 4]), 10);
+    }
+
+    #[test]
+    fn checked_sum_array_detects_overflow() {
+        assert_eq!(checked_sum_array(&[1u32, 2, 3, 4]), Some(10));
+        assert_eq!(checked_sum_array(&[u32::MAX, 1]), None);
+    }
+
+    #[test]
+    fn up3_over_u32_and_a_wide_type() {
+        assert_eq!(up3(6u32), 9);
+        assert_eq!(up3(9u32), 9);
+        assert_eq!(up3(10u32), 27);
+        assert_eq!(up3(10u128), 27);
+        assert_eq!(up3_u64(10), 27);
+    }
+
+    #[test]
+    fn checked_up3_detects_overflow() {
+        assert_eq!(checked_up3(10u32), Some(27));
+        assert_eq!(checked_up3(u32::MAX), None);
+    }
+
+    #[test]
+    fn gcd_over_u32_and_a_wide_type() {
+        assert_eq!(gcd(12u32, 8), 4);
+        assert_eq!(gcd(12u128, 8), 4);
+        assert_eq!(gcd_u64(12, 8), 4);
+    }
+
+    #[test]
+    fn gcd_normalizes_negative_inputs_to_their_magnitude() {
+        assert_eq!(gcd(-12i64, 8), 4);
+        assert_eq!(gcd(12i64, -8), 4);
+        assert_eq!(gcd(-12i64, -8), 4);
+    }
+}
@@ -4,6 +4,8 @@
 
 use std::ops::Mul;
 
+use crate::assignments::assignment09::bigint::BigInt;
+
 /// 2x2 matrix of the following configuration:
 ///
 /// a, b
@@ -66,13 +68,21 @@ impl Mul<Vec2> for Mat2 {
 }
 
 impl Mat2 {
-    /// Calculates the power of matrix.
+    /// Calculates the power of matrix via binary (square-and-multiply) exponentiation, so this
+    /// takes `O(log power)` matrix multiplications instead of `O(power)`. The accumulator starts
+    /// at the identity, so `power == 0` yields the identity matrix.
     fn power(self, power: u64) -> Mat2 {
-        let mut mat = self;
-        for i in 2..=power {
-            mat = mat * self;
+        let mut base = self;
+        let mut remaining = power;
+        let mut result = Mat2::new();
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            remaining >>= 1;
         }
-        mat
+        result
     }
 }
 
@@ -101,6 +111,80 @@ pub fn fibonacci(n: u64) -> u64 {
     (FIBONACCI_MAT.power(n) * FIBONACCI_VEC).get_upper()
 }
 
+/// 2x2 matrix of `BigInt` entries, mirroring [`Mat2`] but with unbounded precision so
+/// [`fibonacci_big`] doesn't overflow `u64` the way [`fibonacci`] does.
+#[derive(Debug, Clone)]
+struct BigMat2 {
+    a: BigInt,
+    b: BigInt,
+    c: BigInt,
+    d: BigInt,
+}
+
+impl BigMat2 {
+    /// Creates an identity matrix.
+    fn new() -> Self {
+        Self {
+            a: BigInt::new(1),
+            b: BigInt::new(0),
+            c: BigInt::new(0),
+            d: BigInt::new(1),
+        }
+    }
+}
+
+impl Mul<BigMat2> for BigMat2 {
+    type Output = BigMat2;
+
+    /// Consult <https://www.mathsisfun.com/algebra/matrix-multiplying.html>
+    fn mul(self, rhs: BigMat2) -> Self::Output {
+        let a = self.a.clone() * rhs.a.clone() + self.b.clone() * rhs.c.clone();
+        let b = self.a * rhs.b.clone() + self.b.clone() * rhs.d.clone();
+        let c = self.c.clone() * rhs.a + self.d.clone() * rhs.c;
+        let d = self.c * rhs.b + self.d * rhs.d;
+
+        Self { a, b, c, d }
+    }
+}
+
+impl BigMat2 {
+    /// Calculates the power of matrix via binary (square-and-multiply) exponentiation, mirroring
+    /// [`Mat2::power`].
+    fn power(self, power: u64) -> BigMat2 {
+        let mut base = self;
+        let mut remaining = power;
+        let mut result = BigMat2::new();
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result = result * base.clone();
+            }
+            base = base.clone() * base;
+            remaining >>= 1;
+        }
+        result
+    }
+}
+
+/// The `BigMat2` used for calculating Fibonacci numbers, mirroring [`FIBONACCI_MAT`].
+fn fibonacci_big_mat() -> BigMat2 {
+    BigMat2 {
+        a: BigInt::new(1),
+        b: BigInt::new(1),
+        c: BigInt::new(1),
+        d: BigInt::new(0),
+    }
+}
+
+/// Calculates the Fibonacci number with unbounded precision, via the same matrix-power identity
+/// as [`fibonacci`], so it no longer overflows `u64` for large `n`.
+///
+/// `fibonacci_big_mat().power(n)` applied to the vector `(1, 0)` gives `(a, c)`, and for this
+/// particular matrix `a` is always the larger of the two (it's `F(n + 1)` to `c`'s `F(n)`), so
+/// unlike [`Vec2::get_upper`] this doesn't need a general-purpose `BigInt` comparison.
+pub fn fibonacci_big(n: u64) -> BigInt {
+    fibonacci_big_mat().power(n).a
+}
+
 /// 2x2 floating-point matrix of the following configuration:
 ///
 /// a, b
@@ -1,15 +1,52 @@
 //! Calculator.
 
 use anyhow::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::io::{self, BufRead, Write};
 
+use super::mod_int::{ModInt, DEFAULT_MODULUS};
+use super::parser::parse_command;
 use super::syntax::{BinOp, Command, Expression};
+use super::vm::{Instr, RunOutcome};
+
+/// The arithmetic mode [`Context::calc_expression`] evaluates under.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Evaluate over `f64`, as before.
+    #[default]
+    Float,
+    /// Evaluate over `ModInt<DEFAULT_MODULUS>` instead: results are exact (no floating-point
+    /// error), and `Divide` fails instead of silently producing a fraction when the divisor isn't
+    /// invertible modulo [`DEFAULT_MODULUS`].
+    Modular,
+}
 
 /// Calculator's context.
 #[derive(Debug, Default, Clone)]
 pub struct Context {
     anonymous_counter: usize,
     variables: HashMap<String, f64>,
+    mode: Mode,
+}
+
+/// Reduces `value` into `[0, modulus)`, going through a signed intermediate so negative values
+/// wrap around to `modulus - n` instead of saturating to `0` the way `as u64` would cast a
+/// negative float.
+fn reduce_mod(value: f64, modulus: i64) -> u64 {
+    (value as i64).rem_euclid(modulus) as u64
+}
+
+/// Converts an `f64` (as stored in [`Context::variables`]) into the `u64` representative
+/// [`ModInt::new`] expects.
+fn to_modulus_representative(value: f64) -> u64 {
+    reduce_mod(value, DEFAULT_MODULUS as i64)
+}
+
+/// Converts a raw (unreduced) exponent into the representative [`ModInt::pow`] expects. Fermat's
+/// little theorem reduces exponents modulo `MOD - 1`, not `MOD`: `a^(MOD-1) == 1` for `a` coprime
+/// to the prime `MOD`, so the exponent's period is `MOD - 1`, one short of the base's.
+fn to_exponent_representative(value: f64) -> u64 {
+    reduce_mod(value, (DEFAULT_MODULUS - 1) as i64)
 }
 
 impl Context {
@@ -23,13 +60,56 @@ impl Context {
         self.anonymous_counter
     }
 
-    /// Calculates the given expression. (We assume the absence of overflow.)
+    /// Sets the arithmetic mode used by [`calc_expression`](Self::calc_expression).
+    pub fn set_mode(&mut self, mode: Mode) {
+        self.mode = mode;
+    }
+
+    /// Executes `program` as a tiny accumulator machine (see [`Instr`]), starting at instruction
+    /// `0` with an accumulator of `0`. Runs until either the instruction pointer moves past the
+    /// end of `program` ([`RunOutcome::Finish`]), or some instruction is about to execute for a
+    /// second time, detected via a `HashSet` of already-visited pointers ([`RunOutcome::Loop`]).
+    pub fn run(program: &[Instr]) -> RunOutcome {
+        let mut visited = HashSet::new();
+        let mut pointer: isize = 0;
+        let mut accumulator: isize = 0;
+
+        loop {
+            if pointer < 0 || pointer as usize >= program.len() {
+                return RunOutcome::Finish(accumulator);
+            }
+            if !visited.insert(pointer) {
+                return RunOutcome::Loop(accumulator);
+            }
+
+            match program[pointer as usize] {
+                Instr::Acc(amount) => {
+                    accumulator += amount;
+                    pointer += 1;
+                }
+                Instr::Jmp(offset) => pointer += offset,
+                Instr::Nop(_) => pointer += 1,
+            }
+        }
+    }
+
+    /// Calculates the given expression, in whichever [`Mode`] the context is set to. (We assume
+    /// the absence of overflow.)
     pub fn calc_expression(&self, expression: &Expression) -> Result<f64> {
+        match self.mode {
+            Mode::Float => self.calc_expression_float(expression),
+            Mode::Modular => self
+                .calc_expression_modular(expression)
+                .map(|value| value.value() as f64),
+        }
+    }
+
+    fn calc_expression_float(&self, expression: &Expression) -> Result<f64> {
         match expression {
             Expression::Num(num) => Ok(*num),
             Expression::BinOp { op, lhs, rhs } => {
-                let left = self.calc_expression(lhs)?;
-                let right = self.calc_expression(rhs)?;
+                let left = self.calc_expression_float(lhs)?;
+                let right = self.calc_expression_float(rhs)?;
                 match op {
                     BinOp::Add => Ok(left + right),
                     BinOp::Subtract => Ok(left - right),
@@ -51,6 +131,44 @@ impl Context {
         }
     }
 
+    /// Calculates the given expression over [`ModInt<DEFAULT_MODULUS>`], for [`Mode::Modular`].
+    /// Variables are recovered from the same `f64`-valued storage [`calc_expression_float`] uses,
+    /// since every `ModInt<DEFAULT_MODULUS>` value round-trips through `f64` exactly.
+    fn calc_expression_modular(
+        &self,
+        expression: &Expression,
+    ) -> Result<ModInt<DEFAULT_MODULUS>> {
+        match expression {
+            Expression::Num(num) => Ok(ModInt::new(to_modulus_representative(*num))),
+            Expression::BinOp { op, lhs, rhs } => {
+                let left = self.calc_expression_modular(lhs)?;
+                // `Power` reduces the exponent modulo `MOD - 1`, not `MOD`, so it needs `rhs`'s
+                // raw (unreduced) value rather than `right`, which has already lost that
+                // distinction by the time it's a `ModInt`.
+                if let BinOp::Power = op {
+                    let exponent = to_exponent_representative(self.calc_expression_float(rhs)?);
+                    return Ok(left.pow(exponent));
+                }
+
+                let right = self.calc_expression_modular(rhs)?;
+                match op {
+                    BinOp::Add => Ok(left + right),
+                    BinOp::Subtract => Ok(left - right),
+                    BinOp::Multiply => Ok(left * right),
+                    BinOp::Divide => right
+                        .inverse()
+                        .map(|inverse| left * inverse)
+                        .ok_or_else(|| Error::msg("division by a non-invertible element")),
+                    BinOp::Power => unreachable!("handled above"),
+                }
+            }
+            Expression::Variable(var) => match self.variables.get(var) {
+                Some(value) => Ok(ModInt::new(to_modulus_representative(*value))),
+                None => Err(Error::msg("message")),
+            },
+        }
+    }
+
     /// Calculates the given command. (We assume the absence of overflow.)
     ///
     /// If there is no variable lhs in the command (i.e. `command.variable = None`), its value should be stored at `$0`, `$1`, `$2`, ... respectively.
@@ -80,3 +198,36 @@ impl Context {
         Ok((var, rc))
     }
 }
+
+/// Runs a REPL (read-eval-print loop) over standard input.
+///
+/// Each line is parsed with [`parse_command`] and evaluated with a single [`Context`] that
+/// persists across lines, so `v = 3 - 2` followed by `v * 2` sees the earlier assignment to `v`.
+/// Parse and evaluation errors are printed to the prompt rather than aborting the loop, since a
+/// typo on one line shouldn't lose the rest of the session.
+pub fn run_repl() -> Result<()> {
+    let mut context = Context::new();
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+
+    loop {
+        print!("> ");
+        stdout.flush()?;
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line)? == 0 {
+            break;
+        }
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        match parse_command(line).and_then(|command| context.calc_command(&command)) {
+            Ok((var, value)) => println!("{var} = {value}"),
+            Err(err) => println!("error: {err}"),
+        }
+    }
+
+    Ok(())
+}
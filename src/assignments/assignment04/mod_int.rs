@@ -0,0 +1,98 @@
+//! Modular integers, for exact arithmetic modulo a fixed prime.
+
+use std::ops::{Add, Div, Mul, Sub};
+
+/// The modulus used by the calculator's [modular mode](super::context::Mode::Modular): a prime
+/// common in competitive-programming contexts, chosen so it's both large enough to be useful and
+/// small enough that every representative value fits exactly in an `f64` (which the calculator
+/// otherwise stores all values as).
+pub const DEFAULT_MODULUS: u64 = 1_000_000_007;
+
+/// An integer modulo the compile-time constant `MOD`, which callers are expected to pick prime so
+/// that [`inverse`](Self::inverse) is defined for every nonzero element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModInt<const MOD: u64> {
+    value: u64,
+}
+
+impl<const MOD: u64> ModInt<MOD> {
+    /// Creates a new `ModInt`, reducing `value` into `[0, MOD)`.
+    pub fn new(value: u64) -> Self {
+        ModInt { value: value % MOD }
+    }
+
+    /// Returns the representative value in `[0, MOD)`.
+    pub fn value(self) -> u64 {
+        self.value
+    }
+
+    /// Raises `self` to the power `exp` via binary (square-and-multiply) exponentiation,
+    /// reducing modulo `MOD` at each step.
+    pub fn pow(self, exp: u64) -> Self {
+        let mut base = self;
+        let mut remaining = exp;
+        let mut result = ModInt::new(1);
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            remaining >>= 1;
+        }
+        result
+    }
+
+    /// Returns the modular inverse of `self` via Fermat's little theorem (`self^(MOD - 2)`, which
+    /// holds when `MOD` is prime and `self` isn't a multiple of it). Returns `None` for `self ==
+    /// 0`, the only non-invertible element this can detect without factoring `MOD`.
+    pub fn inverse(self) -> Option<Self> {
+        if self.value == 0 {
+            None
+        } else {
+            Some(self.pow(MOD - 2))
+        }
+    }
+}
+
+impl<const MOD: u64> Add for ModInt<MOD> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        ModInt::new(self.value + rhs.value)
+    }
+}
+
+impl<const MOD: u64> Sub for ModInt<MOD> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        ModInt::new(self.value + MOD - rhs.value)
+    }
+}
+
+impl<const MOD: u64> Mul for ModInt<MOD> {
+    type Output = Self;
+
+    /// Multiplies via a `u128` intermediate so the product can't overflow `u64` before the
+    /// modular reduction.
+    fn mul(self, rhs: Self) -> Self::Output {
+        let product = self.value as u128 * rhs.value as u128;
+        ModInt::new((product % MOD as u128) as u64)
+    }
+}
+
+impl<const MOD: u64> Div for ModInt<MOD> {
+    type Output = Self;
+
+    /// Divides by multiplying by the modular inverse.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` has no inverse modulo `MOD` (i.e. `rhs` is zero), matching how `Div` panics
+    /// on a zero divisor for the built-in integer types. Callers that need to recover from this
+    /// instead of panicking (like the calculator's modular mode) should check
+    /// [`inverse`](Self::inverse) themselves rather than going through this trait.
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse().expect("division by a non-invertible ModInt")
+    }
+}
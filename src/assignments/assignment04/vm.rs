@@ -0,0 +1,32 @@
+//! A tiny accumulator-machine VM, modeled on the "boot code" interpreter from Advent of Code 2020
+//! day 8: `Acc` adjusts a running accumulator, `Jmp` moves the instruction pointer by a relative
+//! offset, and `Nop` does nothing but advance. Useful for analyzing self-referential command
+//! sequences -- e.g. finding the single `Jmp` <-> `Nop` swap that turns a looping program into one
+//! that terminates -- which the calculator's straight-line [`Context::calc_command`] can't express.
+//!
+//! [`Context::calc_command`]: super::context::Context::calc_command
+
+/// A single VM instruction. The `isize` payload is `Acc`'s amount to add to the accumulator, or
+/// `Jmp`/`Nop`'s relative offset to the instruction pointer (unused for `Nop`, but kept so a `Jmp`
+/// can be turned into a `Nop` in place without dropping its offset).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instr {
+    /// Adds the payload to the accumulator, then advances to the next instruction.
+    Acc(isize),
+    /// Moves the instruction pointer by the payload, relative to this instruction.
+    Jmp(isize),
+    /// Does nothing; advances to the next instruction.
+    Nop(isize),
+}
+
+/// The result of running a program of [`Instr`]s via
+/// [`Context::run`](super::context::Context::run).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunOutcome {
+    /// The instruction pointer moved past the end of the program, with this final accumulator
+    /// value.
+    Finish(isize),
+    /// Some instruction was about to execute a second time, with the accumulator value at that
+    /// point (i.e. right before the repeat would have happened).
+    Loop(isize),
+}
@@ -0,0 +1,134 @@
+//! Complex numbers over a semiring, usable as polynomial coefficients (e.g. `Polynomial<Complex<f64>>`
+//! or `Polynomial<Complex<Rational>>`), following num-complex's arithmetic convention.
+
+use std::fmt;
+
+use super::semiring::{Field, Semiring};
+
+/// A complex number `re + im * i` with components in `C`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Complex<C: Semiring> {
+    /// Real part.
+    pub re: C,
+    /// Imaginary part.
+    pub im: C,
+}
+
+impl<C: Semiring> Complex<C> {
+    /// Creates a new complex number.
+    pub fn new(re: C, im: C) -> Self {
+        Self { re, im }
+    }
+
+    /// Returns the squared norm (modulus), `re^2 + im^2`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cs220::assignments::assignment06::Complex;
+    ///
+    /// assert_eq!(Complex::new(3.0, 4.0).norm_sqr(), 25.0);
+    /// ```
+    pub fn norm_sqr(&self) -> C {
+        self.re.mul(&self.re).add(&self.im.mul(&self.im))
+    }
+
+    /// Returns the complex conjugate, `re - im * i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cs220::assignments::assignment06::Complex;
+    ///
+    /// let conj = Complex::new(3.0, 4.0).conj();
+    /// assert_eq!((conj.re, conj.im), (3.0, -4.0));
+    /// ```
+    pub fn conj(&self) -> Self
+    where
+        C: Field,
+    {
+        Self {
+            re: self.re.clone(),
+            im: self.im.neg(),
+        }
+    }
+
+    /// Subtracts `rhs` from `self`.
+    pub fn sub(&self, rhs: &Self) -> Self
+    where
+        C: Field,
+    {
+        Self {
+            re: self.re.add(&rhs.re.neg()),
+            im: self.im.add(&rhs.im.neg()),
+        }
+    }
+}
+
+/// Multiplication needs subtraction (`ac - bd`), which a bare [`Semiring`] can't provide, so
+/// `Complex<C>` is only itself a `Semiring` when `C` is a [`Field`].
+impl<C: Field> Semiring for Complex<C> {
+    fn zero() -> Self {
+        Self {
+            re: C::zero(),
+            im: C::zero(),
+        }
+    }
+
+    fn one() -> Self {
+        Self {
+            re: C::one(),
+            im: C::zero(),
+        }
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        Self {
+            re: self.re.add(&rhs.re),
+            im: self.im.add(&rhs.im),
+        }
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        let re = self.re.mul(&rhs.re).add(&self.im.mul(&rhs.im).neg());
+        let im = self.re.mul(&rhs.im).add(&self.im.mul(&rhs.re));
+        Self { re, im }
+    }
+}
+
+impl<C: Field> Field for Complex<C> {
+    fn neg(&self) -> Self {
+        Self {
+            re: self.re.neg(),
+            im: self.im.neg(),
+        }
+    }
+
+    /// # Examples
+    ///
+    /// ```
+    /// use cs220::assignments::assignment06::{Complex, Semiring};
+    ///
+    /// // (3 + 4i) * (3 + 4i)^-1 == 1, the multiplicative identity.
+    /// let z = Complex::new(3.0, 4.0);
+    /// let product = Semiring::mul(&z, &z.inv().unwrap());
+    /// assert!((product.re - 1.0).abs() < 1e-12 && product.im.abs() < 1e-12);
+    ///
+    /// // Zero has no multiplicative inverse, same as for a bare field element.
+    /// assert_eq!(Complex::new(0.0, 0.0).inv(), None);
+    /// ```
+    fn inv(&self) -> Option<Self> {
+        let denom_inv = self.norm_sqr().inv()?;
+        let conj = self.conj();
+        Some(Self {
+            re: conj.re.mul(&denom_inv),
+            im: conj.im.mul(&denom_inv),
+        })
+    }
+}
+
+impl<C: Semiring + fmt::Display> fmt::Display for Complex<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}i", self.re, self.im)
+    }
+}
@@ -0,0 +1,205 @@
+//! Root-finding for real univariate polynomials via the Durand-Kerner (Weierstrass) iteration,
+//! and a sum-of-two-squares decomposition built on top of it.
+
+use super::complex::Complex;
+use super::semiring::{Field, Polynomial, Semiring};
+
+/// Maximum number of Durand-Kerner iterations before giving up and returning the current estimate.
+const MAX_ITERATIONS: usize = 500;
+
+/// Stop iterating once every root estimate moves by less than this amount in one step.
+const TOLERANCE: f64 = 1e-12;
+
+/// Evaluates a real polynomial at a complex point via Horner's method.
+fn eval_at_complex(poly: &Polynomial<f64>, point: Complex<f64>) -> Complex<f64> {
+    let degree = match poly.degree() {
+        Some(degree) => degree,
+        None => return Complex::zero(),
+    };
+
+    let mut result = Complex::new(poly.coeff_at(degree), 0.0);
+    for n in (0..degree).rev() {
+        let coeff = Complex::new(poly.coeff_at(n), 0.0);
+        result = result.mul(&point).add(&coeff);
+    }
+    result
+}
+
+impl Polynomial<f64> {
+    /// Finds all complex roots of `self` (with multiplicity) via the Durand-Kerner iteration.
+    ///
+    /// Returns an empty vector for the zero polynomial or a nonzero constant.
+    ///
+    /// # Examples
+    ///
+    /// Finds the four roots of `(x-1)^2*(x^2+1) = x^4 - 2x^3 + 2x^2 - 2x + 1`: a double real root
+    /// at `1` and a conjugate pair `±i`.
+    ///
+    /// ```
+    /// use cs220::assignments::assignment06::{Polynomial, Semiring};
+    ///
+    /// let poly = Polynomial::term(1.0, 4)
+    ///     .add(&Polynomial::term(-2.0, 3))
+    ///     .add(&Polynomial::term(2.0, 2))
+    ///     .add(&Polynomial::term(-2.0, 1))
+    ///     .add(&Polynomial::term(1.0, 0));
+    ///
+    /// let roots = poly.roots();
+    /// assert_eq!(roots.len(), 4);
+    ///
+    /// let (mut reals, mut imaginaries) = (0, 0);
+    /// for root in &roots {
+    ///     if root.im.abs() < 1e-6 {
+    ///         assert!((root.re - 1.0).abs() < 1e-6);
+    ///         reals += 1;
+    ///     } else {
+    ///         assert!(root.re.abs() < 1e-6 && (root.im.abs() - 1.0).abs() < 1e-6);
+    ///         imaginaries += 1;
+    ///     }
+    /// }
+    /// assert_eq!((reals, imaginaries), (2, 2));
+    /// ```
+    pub fn roots(&self) -> Vec<Complex<f64>> {
+        let degree = match self.degree() {
+            Some(degree) if degree >= 1 => degree,
+            _ => return Vec::new(),
+        };
+
+        let monic = self.make_monic();
+
+        // Seed with distinct points spiraling slowly inward (`|0.4 + 0.9i|` is just under 1), the
+        // standard Durand-Kerner starting guess.
+        let seed = Complex::new(0.4, 0.9);
+        let mut estimates: Vec<Complex<f64>> = Vec::with_capacity(degree as usize);
+        let mut power = Complex::one();
+        for _ in 0..degree {
+            power = power.mul(&seed);
+            estimates.push(power.clone());
+        }
+
+        for _ in 0..MAX_ITERATIONS {
+            let mut max_delta: f64 = 0.0;
+            let previous = estimates.clone();
+
+            for i in 0..estimates.len() {
+                let numerator = eval_at_complex(&monic, previous[i].clone());
+                let mut denominator = Complex::one();
+                for (j, other) in previous.iter().enumerate() {
+                    if i != j {
+                        denominator = denominator.mul(&previous[i].sub(other));
+                    }
+                }
+
+                let correction = numerator.mul(&denominator.inv().expect("distinct root estimates stay apart"));
+                estimates[i] = previous[i].sub(&correction);
+                max_delta = max_delta.max(estimates[i].sub(&previous[i]).norm_sqr().sqrt());
+            }
+
+            if max_delta < TOLERANCE {
+                break;
+            }
+        }
+
+        estimates
+    }
+
+    /// Decomposes `self` as `a(x)^2 + b(x)^2` for real polynomials `a` and `b`, i.e. expresses it
+    /// as a sum of two squares.
+    ///
+    /// This is possible exactly when `self` has a nonnegative leading coefficient and every real
+    /// root has even multiplicity (so `self` never changes sign, and never takes the negative
+    /// values a sum of squares can't produce). Returns `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// Decomposes `(x-1)^2*(x^2+1)` as a sum of two squares and reconstructs the original
+    /// polynomial from the result.
+    ///
+    /// ```
+    /// use cs220::assignments::assignment06::{Polynomial, Semiring};
+    ///
+    /// let poly = Polynomial::term(1.0, 4)
+    ///     .add(&Polynomial::term(-2.0, 3))
+    ///     .add(&Polynomial::term(2.0, 2))
+    ///     .add(&Polynomial::term(-2.0, 1))
+    ///     .add(&Polynomial::term(1.0, 0));
+    ///
+    /// let (a, b) = poly.sum_of_two_squares().unwrap();
+    /// let reconstructed = a.mul(&a).add(&b.mul(&b));
+    /// for x in [-2.0, -1.0, 0.0, 0.5, 3.0] {
+    ///     assert!((reconstructed.eval(x) - poly.eval(x)).abs() < 1e-6);
+    /// }
+    /// ```
+    pub fn sum_of_two_squares(&self) -> Option<(Polynomial<f64>, Polynomial<f64>)> {
+        let leading = self.leading_coeff()?;
+        if leading < 0.0 {
+            return None;
+        }
+
+        let roots = self.roots();
+
+        // Group roots into the real ones (im ~ 0) and one representative per complex-conjugate
+        // pair (im > 0); a real polynomial's complex roots always come in conjugate pairs, so
+        // every negative-im root here is matched by some positive-im root elsewhere in the list.
+        let mut real_roots: Vec<f64> = Vec::new();
+        let mut half_plane_roots: Vec<Complex<f64>> = Vec::new();
+        let mut negative_count = 0;
+
+        for root in &roots {
+            if root.im.abs() < TOLERANCE.sqrt() {
+                real_roots.push(root.re);
+            } else if root.im > 0.0 {
+                half_plane_roots.push(root.clone());
+            } else {
+                negative_count += 1;
+            }
+        }
+
+        if negative_count != half_plane_roots.len() {
+            return None;
+        }
+
+        // Every real root must carry even multiplicity: half of each copy feeds `q`.
+        real_roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let mut half_real_roots: Vec<f64> = Vec::new();
+        let mut i = 0;
+        while i < real_roots.len() {
+            let mut count = 1;
+            while i + count < real_roots.len() && (real_roots[i + count] - real_roots[i]).abs() < TOLERANCE.sqrt() {
+                count += 1;
+            }
+            if count % 2 != 0 {
+                return None;
+            }
+            for _ in 0..(count / 2) {
+                half_real_roots.push(real_roots[i]);
+            }
+            i += count;
+        }
+
+        // q(x) = sqrt(leading) * product(x - r) over the half-multiplicity real roots and one
+        // root from each complex-conjugate pair. Then q * conj(q) == self, and splitting q's
+        // coefficients into real and imaginary parts gives a(x)^2 + b(x)^2 == self.
+        let sqrt_leading = Complex::new(leading.sqrt(), 0.0);
+        let mut q = Polynomial::from(sqrt_leading);
+        for root in half_real_roots {
+            let factor = Polynomial::term(Complex::one(), 1).add(&Polynomial::term(Complex::new(-root, 0.0), 0));
+            q = q.mul(&factor);
+        }
+        for root in half_plane_roots {
+            let factor = Polynomial::term(Complex::one(), 1).add(&Polynomial::term(root.neg(), 0));
+            q = q.mul(&factor);
+        }
+
+        let degree = q.degree().unwrap_or(0);
+        let mut a_coeffs = Polynomial::zero();
+        let mut b_coeffs = Polynomial::zero();
+        for n in 0..=degree {
+            let coeff = q.coeff_at(n);
+            a_coeffs = a_coeffs.add(&Polynomial::term(coeff.re, n));
+            b_coeffs = b_coeffs.add(&Polynomial::term(coeff.im, n));
+        }
+
+        Some((a_coeffs, b_coeffs))
+    }
+}
@@ -0,0 +1,185 @@
+//! Rational functions: the field of fractions of [`Polynomial<C>`], kept in canonical form --
+//! numerator and denominator share no common factor and the denominator is monic -- the way
+//! Mathlib's `RatFunc` and Isabelle's `Rational` theory represent fractions of polynomials.
+
+use std::fmt;
+use std::str::FromStr;
+
+use super::semiring::{Field, Polynomial, Semiring};
+
+/// A ratio of two polynomials. Every constructor and operation normalizes the result: the
+/// numerator and denominator are divided by their [`Polynomial::gcd`], and the denominator is
+/// scaled to be monic. A zero denominator is rejected, mirroring how dividing a field element by
+/// zero is rejected.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RationalFunction<C: Field> {
+    numerator: Polynomial<C>,
+    denominator: Polynomial<C>,
+}
+
+impl<C: Field> RationalFunction<C> {
+    /// Creates a rational function from a numerator and denominator, canceling their common
+    /// factor and normalizing the denominator to be monic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is the zero polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cs220::assignments::assignment06::{Polynomial, RationalFunction};
+    ///
+    /// // f(x) = x / (x - 1), with a pole at x = 1.
+    /// let f = RationalFunction::new(Polynomial::x(), Polynomial::x().sub(&Polynomial::from(1.0)));
+    /// assert_eq!(f.eval(2.0), 2.0); // 2 / (2 - 1) = 2
+    /// assert_eq!(f.eval(3.0), 1.5); // 3 / (3 - 1) = 1.5
+    /// ```
+    pub fn new(numerator: Polynomial<C>, denominator: Polynomial<C>) -> Self {
+        assert!(
+            denominator != Polynomial::zero(),
+            "rational function with a zero denominator"
+        );
+
+        let gcd = numerator.gcd(&denominator);
+        let (numerator, _) = numerator.div_rem(&gcd);
+        let (denominator, _) = denominator.div_rem(&gcd);
+
+        let leading = denominator
+            .leading_coeff()
+            .expect("denominator stays nonzero after canceling a factor that divides it evenly");
+        let inv = leading
+            .inv()
+            .expect("a field's nonzero elements are always invertible");
+
+        Self {
+            numerator: numerator.scale(&inv),
+            denominator: denominator.scale(&inv),
+        }
+    }
+
+    /// Wraps a bare polynomial as a rational function over denominator `1`.
+    pub fn from_polynomial(numerator: Polynomial<C>) -> Self {
+        Self {
+            numerator,
+            denominator: Polynomial::one(),
+        }
+    }
+
+    /// Negates the rational function.
+    pub fn neg(&self) -> Self {
+        Self {
+            numerator: self.numerator.neg(),
+            denominator: self.denominator.clone(),
+        }
+    }
+
+    /// Evaluates the rational function at `value`, as `numerator(value) / denominator(value)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is a pole, i.e. the denominator vanishes there.
+    ///
+    /// # Examples
+    ///
+    /// Evaluating at the pole panics, since the denominator vanishes there:
+    ///
+    /// ```should_panic
+    /// use cs220::assignments::assignment06::{Polynomial, RationalFunction};
+    ///
+    /// let f = RationalFunction::new(Polynomial::x(), Polynomial::x().sub(&Polynomial::from(1.0)));
+    /// let _ = f.eval(1.0);
+    /// ```
+    pub fn eval(&self, value: C) -> C {
+        let denominator_value = self.denominator.eval(value.clone());
+        let inv = denominator_value
+            .inv()
+            .expect("rational function evaluated at a pole");
+        self.numerator.eval(value).mul(&inv)
+    }
+}
+
+impl<C: Field> Semiring for RationalFunction<C> {
+    fn zero() -> Self {
+        Self {
+            numerator: Polynomial::zero(),
+            denominator: Polynomial::one(),
+        }
+    }
+
+    fn one() -> Self {
+        Self {
+            numerator: Polynomial::one(),
+            denominator: Polynomial::one(),
+        }
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        let numerator = self
+            .numerator
+            .mul(&rhs.denominator)
+            .add(&rhs.numerator.mul(&self.denominator));
+        let denominator = self.denominator.mul(&rhs.denominator);
+        RationalFunction::new(numerator, denominator)
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        RationalFunction::new(self.numerator.mul(&rhs.numerator), self.denominator.mul(&rhs.denominator))
+    }
+}
+
+impl<C: Field> std::ops::Sub for RationalFunction<C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Semiring::add(&self, &rhs.neg())
+    }
+}
+
+impl<C: Field> std::ops::Div for RationalFunction<C> {
+    type Output = Self;
+
+    /// Divides `self` by `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: Self) -> Self::Output {
+        RationalFunction::new(self.numerator.mul(&rhs.denominator), self.denominator.mul(&rhs.numerator))
+    }
+}
+
+impl<C: Field + fmt::Display> fmt::Display for RationalFunction<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}) / ({})", self.numerator, self.denominator)
+    }
+}
+
+/// Parses an expression over the [`Polynomial`] term grammar combined with `+`, `-`, `*`, and `/`,
+/// each operator and term separated by whitespace (e.g. `"3x^2 + 5x - 2 / x + 1"`). Operators are
+/// applied left to right, with no precedence between them.
+impl<C: Field> FromStr for RationalFunction<C> {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.split_whitespace();
+
+        let first = tokens.next().ok_or(())?;
+        let mut result = RationalFunction::from_polynomial(Polynomial::from_str(first).map_err(|_| ())?);
+
+        while let Some(op) = tokens.next() {
+            let atom = tokens.next().ok_or(())?;
+            let rhs = RationalFunction::from_polynomial(Polynomial::from_str(atom).map_err(|_| ())?);
+
+            result = match op {
+                "+" => result.add(&rhs),
+                "-" => result - rhs,
+                "*" => result.mul(&rhs),
+                "/" => result / rhs,
+                _ => return Err(()),
+            };
+        }
+
+        Ok(result)
+    }
+}
@@ -1,6 +1,6 @@
 //! Semiring
 
-use std::{collections::HashMap, fmt::Debug};
+use std::{collections::HashMap, fmt, fmt::Debug};
 
 /// Semiring.
 ///
@@ -82,6 +82,30 @@ impl Semiring for f64 {
     }
 }
 
+/// A semiring with negation and (partial) multiplicative inverse, i.e. a field.
+///
+/// `inv` returns `None` for zero, the only element a field doesn't invert.
+pub trait Field: Semiring {
+    /// Additive inverse.
+    fn neg(&self) -> Self;
+    /// Multiplicative inverse, or `None` for zero.
+    fn inv(&self) -> Option<Self>;
+}
+
+impl Field for f64 {
+    fn neg(&self) -> Self {
+        -self
+    }
+
+    fn inv(&self) -> Option<Self> {
+        if *self == 0.0 {
+            None
+        } else {
+            Some(1.0 / self)
+        }
+    }
+}
+
 /// Polynomials with coefficient in `C`.
 ///
 /// For example, polynomial `x^2 + 5x + 6` is represented in `Polynomial<u64>` as follows:
@@ -181,6 +205,126 @@ impl<C: Semiring> Polynomial<C> {
         let _ = map.insert(n, a);
         Polynomial { coefficients: map }
     }
+
+    /// Returns the coefficient of `x^degree`, or `C::zero()` if it isn't present.
+    pub fn coeff_at(&self, degree: u64) -> C {
+        self.coefficients.get(&degree).cloned().unwrap_or_else(C::zero)
+    }
+}
+
+impl<C: Field> Polynomial<C> {
+    /// Returns the polynomial's degree (the highest exponent with a nonzero coefficient), or
+    /// `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<u64> {
+        self.coefficients.keys().max().copied()
+    }
+
+    /// Returns the leading (highest-degree) coefficient, or `None` for the zero polynomial.
+    pub fn leading_coeff(&self) -> Option<C> {
+        self.degree().map(|degree| self.coefficients[&degree].clone())
+    }
+
+    /// Negates every coefficient.
+    pub fn neg(&self) -> Self {
+        let coefficients = self.coefficients.iter().map(|(k, v)| (*k, v.neg())).collect();
+        Polynomial { coefficients }
+    }
+
+    /// Subtracts `rhs` from `self`.
+    pub fn sub(&self, rhs: &Self) -> Self {
+        self.add(&rhs.neg())
+    }
+
+    /// Scales every coefficient by `scalar`.
+    pub fn scale(&self, scalar: &C) -> Self {
+        let mut coefficients: HashMap<u64, C> =
+            self.coefficients.iter().map(|(k, v)| (*k, v.mul(scalar))).collect();
+        coefficients.retain(|_, v| *v != C::zero());
+        Polynomial { coefficients }
+    }
+
+    /// Scales the polynomial so its leading coefficient is `1`. The zero polynomial is returned
+    /// unchanged.
+    pub fn make_monic(&self) -> Self {
+        match self.leading_coeff() {
+            None => self.clone(),
+            Some(leading) => {
+                let inv = leading
+                    .inv()
+                    .expect("leading coefficient of a nonzero polynomial is itself nonzero");
+                self.scale(&inv)
+            }
+        }
+    }
+
+    /// Divides `self` by `divisor` via schoolbook long division, returning `(quotient,
+    /// remainder)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is the zero polynomial.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let divisor_degree = divisor.degree().expect("division by the zero polynomial");
+        let divisor_leading = divisor.leading_coeff().expect("divisor has a degree, so it isn't zero");
+        let divisor_inv = divisor_leading
+            .inv()
+            .expect("leading coefficient of a nonzero polynomial is itself nonzero");
+
+        let mut remainder = self.clone();
+        let mut quotient = Polynomial::zero();
+
+        while let Some(remainder_degree) = remainder.degree() {
+            if remainder_degree < divisor_degree {
+                break;
+            }
+
+            let remainder_leading = remainder.leading_coeff().expect("remainder has a degree, so it isn't zero");
+            let term_coeff = remainder_leading.mul(&divisor_inv);
+            let term = Polynomial::term(term_coeff, remainder_degree - divisor_degree);
+
+            quotient = quotient.add(&term);
+            remainder = remainder.sub(&term.mul(divisor));
+        }
+
+        (quotient, remainder)
+    }
+
+    /// Returns the monic Greatest Common Divisor of `self` and `other`, via the Euclidean
+    /// algorithm.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while b != Polynomial::zero() {
+            let (_, remainder) = a.div_rem(&b);
+            a = b;
+            b = remainder;
+        }
+        a.make_monic()
+    }
+}
+
+impl<C: Semiring + fmt::Display> fmt::Display for Polynomial<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.coefficients.is_empty() {
+            return write!(f, "0");
+        }
+
+        let mut degrees: Vec<&u64> = self.coefficients.keys().collect();
+        degrees.sort_unstable_by(|a, b| b.cmp(a));
+
+        let terms: Vec<String> = degrees
+            .into_iter()
+            .map(|degree| {
+                let coeff = &self.coefficients[degree];
+                match degree {
+                    0 => format!("{coeff}"),
+                    1 => format!("{coeff}*x"),
+                    _ => format!("{coeff}*x^{degree}"),
+                }
+            })
+            .collect();
+
+        write!(f, "{}", terms.join(" + "))
+    }
 }
 
 impl<C: Semiring> From<C> for Polynomial<C> {
@@ -1,18 +1,22 @@
 //! Symbolic differentiation with rational coefficents.
 
 use core::num;
+use std::cmp::Ordering;
 use std::fmt;
 use std::ops::*;
 
+use super::semiring::{Field, Semiring};
+
 /// Rational number represented by two isize, numerator and denominator.
 ///
-/// Each Rational number should be normalized so that `demoninator` is nonnegative and `numerator` and `demoninator` are coprime.
-/// See `normalize` for examples. As a corner case, 0 is represented by Rational { numerator: 0, demoninator: 0 }.
+/// Each Rational number should be normalized so that `denominator` is strictly positive (sign
+/// lives on `numerator`) and `numerator` and `denominator` are coprime. See `simplify` for
+/// details. Zero is canonically represented by `Rational { numerator: 0, denominator: 1 }`.
 ///
 /// For "natural use", Rational also overloads standard arithmetic operations, i.e, `+`, `-`, `*`, `/`.
 ///
 /// See [here](https://doc.rust-lang.org/core/ops/index.html) for details.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Rational {
     numerator: isize,
     denominator: isize,
@@ -21,7 +25,7 @@ pub struct Rational {
 // Some useful constants.
 
 /// Zero
-pub const ZERO: Rational = Rational::new(0, 0);
+pub const ZERO: Rational = Rational::new(0, 1);
 /// One
 pub const ONE: Rational = Rational::new(1, 1);
 /// Minus one
@@ -45,6 +49,10 @@ impl Rational {
     }
     /// simplify origin result
     pub fn simplify(self) -> Rational {
+        if self.numerator == 0 {
+            return ZERO;
+        }
+
         let mut negative = false;
         if (self.numerator < 0) != (self.denominator < 0) {
             negative = true;
@@ -61,6 +69,16 @@ impl Rational {
             denominator: denominator / factor,
         }
     }
+
+    /// Returns the reciprocal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    pub fn recip(&self) -> Rational {
+        assert!(self.numerator != 0, "reciprocal of zero");
+        Rational::new(self.denominator, self.numerator).simplify()
+    }
 }
 
 impl Add for Rational {
@@ -119,6 +137,60 @@ impl Div for Rational {
     }
 }
 
+impl Semiring for Rational {
+    fn zero() -> Self {
+        ZERO
+    }
+
+    fn one() -> Self {
+        ONE
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        *self + *rhs
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        *self * *rhs
+    }
+}
+
+impl Field for Rational {
+    fn neg(&self) -> Self {
+        -*self
+    }
+
+    fn inv(&self) -> Option<Self> {
+        if *self == ZERO {
+            None
+        } else {
+            Some(self.recip())
+        }
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Rational::new(-self.numerator, self.denominator)
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    /// Compares `a/b` against `c/d` by cross-multiplying `a*d` against `c*b`, which is valid since
+    /// both denominators are always strictly positive under the canonical form.
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numerator * other.denominator).cmp(&(other.numerator * self.denominator))
+    }
+}
+
 /// Differentiable functions.
 ///
 /// For simplicity, we only consider infinitely differentiable functions.
@@ -172,8 +244,8 @@ impl Differentiable for SingletonPolynomial {
         match self {
             SingletonPolynomial::Const(_) => SingletonPolynomial::Const(ZERO),
             SingletonPolynomial::Polynomial { coeff, power } => {
-                let new_coeff = coeff.mul(*power).simplify();
-                let new_power = power.sub(ONE).simplify();
+                let new_coeff = (*coeff * *power).simplify();
+                let new_power = (*power - ONE).simplify();
                 SingletonPolynomial::Polynomial {
                     coeff: new_coeff,
                     power: new_power,
@@ -242,7 +314,7 @@ impl Differentiable for Trignometric {
         match self {
             Trignometric::Sine { coeff } => Trignometric::Cosine { coeff: *coeff },
             Trignometric::Cosine { coeff } => Trignometric::Sine {
-                coeff: coeff.mul(MINUS_ONE).simplify(),
+                coeff: (*coeff * MINUS_ONE).simplify(),
             },
         }
     }
@@ -324,6 +396,124 @@ impl<F: Differentiable> Differentiable for ComplexFuncs<F> {
     }
 }
 
+impl ComplexFuncs<BaseFuncs> {
+    /// Returns `true` if `self` is the constant `0`.
+    fn is_zero(&self) -> bool {
+        matches!(self, ComplexFuncs::Func(BaseFuncs::Const(r)) if *r == ZERO)
+    }
+
+    /// Returns `true` if `self` is the constant `1`.
+    fn is_one(&self) -> bool {
+        matches!(self, ComplexFuncs::Func(BaseFuncs::Const(r)) if *r == ONE)
+    }
+
+    /// Returns the value of `self`, if it is a bare constant.
+    fn as_const(&self) -> Option<Rational> {
+        match self {
+            ComplexFuncs::Func(BaseFuncs::Const(r)) => Some(*r),
+            _ => None,
+        }
+    }
+
+    /// Counts the operator nodes in the expression tree (every variant but a leaf `Func`).
+    ///
+    /// Useful alongside [`simplify`](Self::simplify) to compare how much a rewrite shrank a
+    /// derivative.
+    pub fn ops_count(&self) -> usize {
+        match self {
+            ComplexFuncs::Func(_) => 0,
+            ComplexFuncs::Add(l, r)
+            | ComplexFuncs::Sub(l, r)
+            | ComplexFuncs::Mul(l, r)
+            | ComplexFuncs::Div(l, r)
+            | ComplexFuncs::Comp(l, r) => 1 + l.ops_count() + r.ops_count(),
+        }
+    }
+
+    /// Simplifies `self` by applying algebraic rewrite rules bottom-up until a fixpoint: additive
+    /// and multiplicative identities/absorbers (`x + 0`, `x * 0`, `x * 1`, `x / 1`, ...), constant
+    /// folding through `Rational` arithmetic, and collapsing a composition down to its outer side
+    /// once both sides have folded to constants. Without this, `diff` on a product or
+    /// composition doubles the node count on every call, so repeated differentiation quickly
+    /// produces unreadably large trees.
+    pub fn simplify(&self) -> Self {
+        let mut current = self.simplify_step();
+        loop {
+            let next = current.simplify_step();
+            if next == current {
+                return next;
+            }
+            current = next;
+        }
+    }
+
+    /// Applies one bottom-up pass of the rewrite rules described in [`simplify`](Self::simplify).
+    fn simplify_step(&self) -> Self {
+        match self {
+            ComplexFuncs::Func(_) => self.clone(),
+            ComplexFuncs::Add(l, r) => {
+                let (l, r) = (l.simplify_step(), r.simplify_step());
+                if l.is_zero() {
+                    r
+                } else if r.is_zero() {
+                    l
+                } else if let (Some(a), Some(b)) = (l.as_const(), r.as_const()) {
+                    ComplexFuncs::Func(BaseFuncs::Const(a.add(b).simplify()))
+                } else {
+                    ComplexFuncs::Add(Box::new(l), Box::new(r))
+                }
+            }
+            ComplexFuncs::Sub(l, r) => {
+                let (l, r) = (l.simplify_step(), r.simplify_step());
+                if r.is_zero() {
+                    l
+                } else if let (Some(a), Some(b)) = (l.as_const(), r.as_const()) {
+                    ComplexFuncs::Func(BaseFuncs::Const(a.sub(b).simplify()))
+                } else {
+                    ComplexFuncs::Sub(Box::new(l), Box::new(r))
+                }
+            }
+            ComplexFuncs::Mul(l, r) => {
+                let (l, r) = (l.simplify_step(), r.simplify_step());
+                if l.is_zero() || r.is_zero() {
+                    ComplexFuncs::Func(BaseFuncs::Const(ZERO))
+                } else if l.is_one() {
+                    r
+                } else if r.is_one() {
+                    l
+                } else if let (Some(a), Some(b)) = (l.as_const(), r.as_const()) {
+                    ComplexFuncs::Func(BaseFuncs::Const(a.mul(b).simplify()))
+                } else {
+                    ComplexFuncs::Mul(Box::new(l), Box::new(r))
+                }
+            }
+            ComplexFuncs::Div(l, r) => {
+                let (l, r) = (l.simplify_step(), r.simplify_step());
+                if r.is_one() {
+                    l
+                } else if let (Some(a), Some(b)) = (l.as_const(), r.as_const()) {
+                    ComplexFuncs::Func(BaseFuncs::Const(a.div(b).simplify()))
+                } else {
+                    ComplexFuncs::Div(Box::new(l), Box::new(r))
+                }
+            }
+            ComplexFuncs::Comp(outer, inner) => {
+                let (outer, inner) = (outer.simplify_step(), inner.simplify_step());
+                // If the inner function is constant, the composition no longer depends on `x` --
+                // it's just `outer` applied to that constant. We can only fold that exactly when
+                // `outer` is itself a bare constant too (composing a constant with anything yields
+                // that same constant); collapsing through a non-constant outer, e.g. `sin`, would
+                // need to evaluate it exactly at `inner`'s value, which a `Rational` can't
+                // represent in general.
+                match (outer.as_const(), inner.as_const()) {
+                    (Some(c), Some(_)) => ComplexFuncs::Func(BaseFuncs::Const(c)),
+                    _ => ComplexFuncs::Comp(Box::new(outer), Box::new(inner)),
+                }
+            }
+        }
+    }
+}
+
 /// Evaluate functions.
 pub trait Evaluate {
     ///  Evaluate `self` at `x`.
@@ -388,9 +578,7 @@ impl<F: Evaluate> Evaluate for ComplexFuncs<F> {
 
 impl fmt::Display for Rational {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if *self == ZERO {
-            return write!(f, "0");
-        } else if self.denominator == 1 {
+        if self.denominator == 1 {
             return write!(f, "{}", self.numerator);
         }
         write!(f, "{}/{}", self.numerator, self.denominator)
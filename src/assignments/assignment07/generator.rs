@@ -32,42 +32,58 @@ impl<T, S> Iterator for Generator<T, S> {
     }
 }
 
+impl<T> Generator<T, ()> {
+    /// Builds a generator from a closure that may capture environment state (counters,
+    /// accumulators, RNG seeds, ...) in a Python-generator style, instead of threading everything
+    /// through an explicit state type `S`. Returns `None` from `f` to stop the generator.
+    pub fn from_fn(f: impl FnMut() -> Option<T> + 'static) -> ClosureGen<T> {
+        ClosureGen { f: Box::new(f) }
+    }
+}
+
+/// A generator backed by a boxed `FnMut` closure, returned by [`Generator::from_fn`].
+#[allow(missing_debug_implementations)]
+pub struct ClosureGen<T> {
+    f: Box<dyn FnMut() -> Option<T>>,
+}
+
+impl<T> Iterator for ClosureGen<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.f)()
+    }
+}
+
 /// Returns a generator that yields fibonacci numbers.
 ///
 /// HINT: Consult <https://en.wikipedia.org/wiki/Fibonacci_sequence>
-pub fn fib_generator(first: usize, second: usize) -> Generator<usize, (usize, usize)> {
-    fn compute(state: &mut (usize, usize)) -> Yielded<usize> {
-        let rc = Yielded::Value(state.0);
-        *state = (state.1, state.0 + state.1);
-        rc
-    }
-    Generator {
-        state: (first, second),
-        f: compute,
-    }
+pub fn fib_generator(first: usize, second: usize) -> ClosureGen<usize> {
+    let mut state = (first, second);
+    Generator::from_fn(move || {
+        let value = state.0;
+        state = (state.1, state.0 + state.1);
+        Some(value)
+    })
 }
 
 /// Returns a generator that yields collatz numbers.
 ///
 /// HINT: Consult <https://en.wikipedia.org/wiki/Collatz_conjecture>
-pub fn collatz_conjecture(start: usize) -> Generator<usize, usize> {
-    fn compute(state: &mut usize) -> Yielded<usize> {
-        if *state == 0 {
-            return Yielded::Stop;
+pub fn collatz_conjecture(start: usize) -> ClosureGen<usize> {
+    let mut state = start;
+    Generator::from_fn(move || {
+        if state == 0 {
+            return None;
         }
-        let rc = Yielded::Value(*state);
-        if *state == 1 {
-            *state = 0;
-        } else if *state % 2 == 0 {
-            *state /= 2;
+        let value = state;
+        if state == 1 {
+            state = 0;
+        } else if state % 2 == 0 {
+            state /= 2;
         } else {
-            *state = *state * 3 + 1;
+            state = state * 3 + 1;
         }
-        rc
-    }
-
-    Generator {
-        state: start,
-        f: compute,
-    }
+        Some(value)
+    })
 }
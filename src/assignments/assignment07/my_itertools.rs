@@ -59,7 +59,6 @@ impl<I: Iterator> Iterator for Enumerate<I> {
     type Item = (usize, I::Item);
 
     fn next(&mut self) -> Option<Self::Item> {
-        let iter_rc = self.iter.next();
         if let Some(item) = self.iter.next() {
             let rc = Some((self.cur, item));
             self.cur += 1;
@@ -70,6 +69,69 @@ impl<I: Iterator> Iterator for Enumerate<I> {
     }
 }
 
+/// Iterator that removes consecutive duplicate elements, keeping only the first of each run.
+/// Non-consecutive duplicates (e.g. `[1, 2, 1]`) are left alone, unlike [`Unique`]'s global
+/// dedup.
+#[allow(missing_debug_implementations)]
+pub struct Dedup<I: Iterator> {
+    iter: I,
+    last: Option<I::Item>,
+}
+
+impl<I: Iterator> Iterator for Dedup<I>
+where
+    I::Item: PartialEq + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for item in self.iter.by_ref() {
+            if self.last.as_ref() != Some(&item) {
+                self.last = Some(item.clone());
+                return Some(item);
+            }
+        }
+        None
+    }
+}
+
+/// Iterator that groups consecutive elements sharing the same key, as computed by a key function.
+#[allow(missing_debug_implementations)]
+pub struct GroupBy<I: Iterator, K, F> {
+    iter: I,
+    key_fn: F,
+    // The first element of the next group, already pulled off `iter` while looking for the end
+    // of the previous one.
+    peeked: Option<(K, I::Item)>,
+}
+
+impl<I: Iterator, K: PartialEq, F: FnMut(&I::Item) -> K> Iterator for GroupBy<I, K, F> {
+    type Item = (K, Vec<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, first) = match self.peeked.take() {
+            Some(pair) => pair,
+            None => {
+                let item = self.iter.next()?;
+                let key = (self.key_fn)(&item);
+                (key, item)
+            }
+        };
+
+        let mut group = vec![first];
+        for item in self.iter.by_ref() {
+            let item_key = (self.key_fn)(&item);
+            if item_key == key {
+                group.push(item);
+            } else {
+                self.peeked = Some((item_key, item));
+                break;
+            }
+        }
+        Some((key, group))
+    }
+}
+
 /// Iterator that zips two iterators together.
 ///
 /// If one iterator is longer than the other one, the remaining elements for the longer element
@@ -149,6 +211,57 @@ pub trait MyIterTools: Iterator {
         }
         sum
     }
+
+    /// Returns an iterator that removes consecutive duplicate elements from `self`, keeping only
+    /// the first of each run.
+    fn my_dedup(self) -> Dedup<Self>
+    where
+        Self: Sized,
+    {
+        Dedup {
+            iter: self,
+            last: None,
+        }
+    }
+
+    /// Returns an iterator that groups consecutive elements of `self` sharing the same key, as
+    /// computed by `key_fn`.
+    fn my_group_by<K, F>(self, key_fn: F) -> GroupBy<Self, K, F>
+    where
+        Self: Sized,
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        GroupBy {
+            iter: self,
+            key_fn,
+            peeked: None,
+        }
+    }
+
+    /// Returns the index of the first element for which `pred` returns `false`, assuming `self`
+    /// is partitioned so every element satisfying `pred` comes before every element that doesn't
+    /// (mirroring [`[T]::partition_point`](slice::partition_point), generalized to iterators).
+    /// Since an `Iterator` doesn't support random access, this collects `self` first and then
+    /// binary-searches the buffer rather than scanning it linearly.
+    fn my_partition_point<P>(self, mut pred: P) -> usize
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        let items: Vec<Self::Item> = self.collect();
+        let mut low = 0;
+        let mut high = items.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if pred(&items[mid]) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
 }
 
 impl<T: ?Sized> MyIterTools for T where T: Iterator {}
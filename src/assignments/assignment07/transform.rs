@@ -6,6 +6,17 @@ use std::ops::Add;
 pub trait Transform<T> {
     /// Transforms value.
     fn transform(&self, value: T) -> T;
+
+    /// Chains `self` with `other`, applying `self` first and `other` to the result.
+    fn and_then<Tr: Transform<T>>(self, other: Tr) -> Compose<Self, Tr>
+    where
+        Self: Sized,
+    {
+        Compose {
+            first: self,
+            second: other,
+        }
+    }
 }
 
 impl<T1, T2, Tr1: Transform<T1>, Tr2: Transform<T2>> Transform<(T1, T2)> for (Tr1, Tr2) {
@@ -17,6 +28,19 @@ impl<T1, T2, Tr1: Transform<T1>, Tr2: Transform<T2>> Transform<(T1, T2)> for (Tr
     }
 }
 
+/// Composes two transformations: applies `A` then `B`. Built via [`Transform::and_then`].
+#[derive(Debug, Clone, Copy)]
+pub struct Compose<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<T, A: Transform<T>, B: Transform<T>> Transform<T> for Compose<A, B> {
+    fn transform(&self, value: T) -> T {
+        self.second.transform(self.first.transform(value))
+    }
+}
+
 /// Identity transformation.
 #[derive(Debug, Clone, Copy)]
 pub struct Identity;
@@ -108,3 +132,98 @@ impl<T: Clone + Eq, Tr: Transform<T>> Transform<T> for RepeatUntilConverge<T, Tr
         previous
     }
 }
+
+/// Repeats transformation until it converges, or `max_iters` iterations have run, whichever
+/// comes first.
+///
+/// Unlike [`RepeatUntilConverge`], this always terminates, which matters for inputs that
+/// oscillate instead of settling on a fixed point.
+#[derive(Debug, Clone, Copy)]
+pub struct RepeatUntilConvergeBounded<T: Eq, Tr: Transform<T>> {
+    inner: Tr,
+    max_iters: u32,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Clone + Eq, Tr: Transform<T>> RepeatUntilConvergeBounded<T, Tr> {
+    /// Creates a new bounded repeat-until-converge transformation.
+    pub fn new(inner: Tr, max_iters: u32) -> Self {
+        RepeatUntilConvergeBounded {
+            inner,
+            max_iters,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone + Eq, Tr: Transform<T>> Transform<T> for RepeatUntilConvergeBounded<T, Tr> {
+    fn transform(&self, value: T) -> T {
+        let mut previous = value;
+        for _ in 0..self.max_iters {
+            let cur = self.inner.transform(previous.clone());
+            if cur == previous {
+                return cur;
+            }
+            previous = cur;
+        }
+        previous
+    }
+}
+
+/// Repeats transformation until two consecutive values are close enough, per a caller-supplied
+/// `close_enough` predicate, or `max_iters` iterations have run.
+///
+/// This is [`RepeatUntilConvergeBounded`] for types like `f64` where exact equality is the wrong
+/// notion of convergence: a Newton's-method or logistic-map iteration should stop once
+/// `|cur - prev| < eps`, not when `cur == prev` bit-for-bit.
+///
+/// # Examples
+///
+/// ```
+/// use cs220::assignments::assignment07::{Custom, RepeatWithTolerance, Transform};
+///
+/// // Newton's method for sqrt(2): x_{n+1} = (x_n + 2 / x_n) / 2.
+/// let newton_sqrt2 = Custom::from(|x: f64| (x + 2.0 / x) / 2.0);
+/// let converge = RepeatWithTolerance::new(newton_sqrt2, |cur: &f64, prev: &f64| (cur - prev).abs() < 1e-9, 100);
+/// assert!((converge.transform(1.0) - std::f64::consts::SQRT_2).abs() < 1e-9);
+///
+/// // x -> -x never converges, so the bound kicks in instead.
+/// let oscillator = Custom::from(|x: f64| -x);
+/// let bounded = RepeatWithTolerance::new(oscillator, |cur: &f64, prev: &f64| (cur - prev).abs() < 1e-9, 5);
+/// assert_eq!(bounded.transform(1.0), -1.0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RepeatWithTolerance<T, Tr: Transform<T>, P: Fn(&T, &T) -> bool> {
+    inner: Tr,
+    close_enough: P,
+    max_iters: u32,
+    _marker: PhantomData<T>,
+}
+
+impl<T, Tr: Transform<T>, P: Fn(&T, &T) -> bool> RepeatWithTolerance<T, Tr, P> {
+    /// Creates a new tolerance-bounded repeat transformation.
+    pub fn new(inner: Tr, close_enough: P, max_iters: u32) -> Self {
+        RepeatWithTolerance {
+            inner,
+            close_enough,
+            max_iters,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone, Tr: Transform<T>, P: Fn(&T, &T) -> bool> Transform<T>
+    for RepeatWithTolerance<T, Tr, P>
+{
+    fn transform(&self, value: T) -> T {
+        let mut previous = value;
+        for _ in 0..self.max_iters {
+            let cur = self.inner.transform(previous.clone());
+            if (self.close_enough)(&cur, &previous) {
+                return cur;
+            }
+            previous = cur;
+        }
+        previous
+    }
+}
@@ -9,6 +9,7 @@
 //! - <https://en.wikipedia.org/wiki/Church_encoding>
 //! - <https://opendsa-server.cs.vt.edu/OpenDSA/Books/PL/html/ChurchNumerals.html>
 
+use std::cell::RefCell;
 use std::rc::Rc;
 
 /// Church numerals are represented as higher-order functions that take a function `f`
@@ -63,6 +64,338 @@ pub fn exp<T: 'static>(n: usize, m: usize) -> Church<T> {
     m_church(n_church)
 }
 
+/// Implement a function to subtract 1 from a given Church numeral. `pred(zero())` is `zero()`.
+///
+/// The usual encoding computes `pred` by running a pair-transforming step `n` times starting
+/// from `(zero, zero)` and keeping the first component, but `Church<T>` can only iterate a single
+/// `Fn(T) -> T`, so there is no type through which to thread a `(Church<T>, Church<T>)` pair
+/// directly. Instead we smuggle the running pair through a `RefCell` captured by a step function
+/// that is the identity on `T`: `n` still drives the iteration count by calling the step, and the
+/// pair update happens as a side effect alongside it.
+///
+/// # Examples
+///
+/// ```
+/// use cs220::assignments::assignment08::{from_usize, pred, to_usize};
+///
+/// assert_eq!(to_usize(pred(from_usize::<usize>(5))), 4);
+/// assert_eq!(to_usize(pred(from_usize::<usize>(0))), 0);
+/// ```
+pub fn pred<T: 'static>(n: Church<T>) -> Church<T> {
+    Rc::new(move |f: Rc<dyn Fn(T) -> T>| {
+        let n = n.clone();
+        Rc::new(move |x: T| {
+            let pair: Rc<RefCell<(Church<T>, Church<T>)>> =
+                Rc::new(RefCell::new((zero(), zero())));
+            let pair_for_step = pair.clone();
+            let step: Rc<dyn Fn(T) -> T> = Rc::new(move |v: T| {
+                let (_, b) = pair_for_step.borrow().clone();
+                let next = succ(b.clone());
+                *pair_for_step.borrow_mut() = (b, next);
+                v
+            });
+            let x = n(step)(x);
+            let predecessor = pair.borrow().0.clone();
+            predecessor(f.clone())(x)
+        })
+    })
+}
+
+/// Implement truncated (monus) subtraction: `sub(n, m)` is `n - m` if `n >= m`, `zero()` otherwise.
+///
+/// `sub(n, m)` is `m` applications of [`pred`] to `n`, driven the same way `pred` itself is
+/// driven: the running accumulator lives in a `RefCell` updated as a side effect of `m`'s
+/// iteration.
+///
+/// # Examples
+///
+/// ```
+/// use cs220::assignments::assignment08::{from_usize, sub, to_usize};
+///
+/// assert_eq!(to_usize(sub(from_usize::<usize>(7), from_usize::<usize>(10))), 0);
+/// assert_eq!(to_usize(sub(from_usize::<usize>(10), from_usize::<usize>(3))), 7);
+/// ```
+pub fn sub<T: 'static>(n: Church<T>, m: Church<T>) -> Church<T> {
+    Rc::new(move |f: Rc<dyn Fn(T) -> T>| {
+        let n = n.clone();
+        let m = m.clone();
+        Rc::new(move |x: T| {
+            let acc: Rc<RefCell<Church<T>>> = Rc::new(RefCell::new(n.clone()));
+            let acc_for_step = acc.clone();
+            let step: Rc<dyn Fn(T) -> T> = Rc::new(move |v: T| {
+                let current = acc_for_step.borrow().clone();
+                *acc_for_step.borrow_mut() = pred(current);
+                v
+            });
+            let x = m(step)(x);
+            let result = acc.borrow().clone();
+            result(f.clone())(x)
+        })
+    })
+}
+
+/// Returns whether the given Church numeral is zero, without converting through [`to_usize`].
+///
+/// Follows Rosetta's "arithmetic operations as functions on numerals" approach: apply the numeral
+/// to a function that always flips a sentinel flag to `false`, starting from a base value of
+/// `true`. Zero never applies the function, so the flag stays `true`.
+pub fn is_zero<T: 'static + Default>(n: Church<T>) -> bool {
+    let flag = Rc::new(RefCell::new(true));
+    let flag_for_step = flag.clone();
+    let f: Rc<dyn Fn(T) -> T> = Rc::new(move |_| {
+        *flag_for_step.borrow_mut() = false;
+        T::default()
+    });
+    let _ = n(f)(T::default());
+    *flag.borrow()
+}
+
+/// Returns whether `n <= m`, implemented as `is_zero(sub(n, m))` now that truncated subtraction
+/// clamps at zero.
+///
+/// # Examples
+///
+/// ```
+/// use cs220::assignments::assignment08::{from_usize, leq};
+///
+/// assert!(leq(from_usize::<usize>(3), from_usize::<usize>(5)));
+/// assert!(!leq(from_usize::<usize>(5), from_usize::<usize>(3)));
+/// ```
+pub fn leq<T: 'static + Default>(n: Church<T>, m: Church<T>) -> bool {
+    is_zero(sub(n, m))
+}
+
+/// Returns whether `n` and `m` represent the same natural number.
+///
+/// # Examples
+///
+/// ```
+/// use cs220::assignments::assignment08::{eq, from_usize};
+///
+/// assert!(eq(from_usize::<usize>(4), from_usize::<usize>(4)));
+/// assert!(!eq(from_usize::<usize>(4), from_usize::<usize>(5)));
+/// ```
+pub fn eq<T: 'static + Default>(n: Church<T>, m: Church<T>) -> bool {
+    leq(n.clone(), m.clone()) && leq(m, n)
+}
+
+/// Counts how many times `m` fits into `n` and the remainder left over, both via repeated
+/// truncated subtraction. Shared by [`div`] and [`rem`] so neither recomputes the other.
+fn div_rem<T: 'static + Default>(n: Church<T>, m: Church<T>) -> (Church<T>, Church<T>) {
+    if is_zero(m.clone()) {
+        return (zero(), n);
+    }
+    let mut remainder = n;
+    let mut quotient = zero();
+    while leq(m.clone(), remainder.clone()) {
+        remainder = sub(remainder, m.clone());
+        quotient = succ(quotient);
+    }
+    (quotient, remainder)
+}
+
+/// Implement integer division via repeated truncated subtraction, counting how many times `m`
+/// fits into `n`. Returns `zero()` when `m` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use cs220::assignments::assignment08::{div, from_usize, to_usize};
+///
+/// assert_eq!(to_usize(div(from_usize::<usize>(17), from_usize::<usize>(5))), 3);
+/// ```
+pub fn div<T: 'static + Default>(n: Church<T>, m: Church<T>) -> Church<T> {
+    div_rem(n, m).0
+}
+
+/// Implement the remainder of dividing `n` by `m` via the same repeated-subtraction process as
+/// [`div`]. Returns `n` unchanged when `m` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use cs220::assignments::assignment08::{from_usize, rem, to_usize};
+///
+/// assert_eq!(to_usize(rem(from_usize::<usize>(17), from_usize::<usize>(5))), 2);
+/// ```
+pub fn rem<T: 'static + Default>(n: Church<T>, m: Church<T>) -> Church<T> {
+    div_rem(n, m).1
+}
+
+/// Converts a Church numeral to a `usize`, returning `None` instead of wrapping once the count
+/// would exceed `usize::MAX`.
+///
+/// Driven the same way as [`is_zero`]: apply `n` to an `inc` step that threads a saturating
+/// counter through a `RefCell` side channel. Once the counter would overflow, `inc` is recorded as
+/// `None` and every further application is a cheap no-op rather than doing real work, but `n`
+/// applications of `inc` still happen — for a numeral built through [`exp`] or [`mult`] with an
+/// astronomically large value, that loop itself is the bottleneck. [`CountedChurch`] avoids it
+/// entirely by tracking the value alongside the closure instead of recovering it by iterating.
+///
+/// # Examples
+///
+/// ```
+/// use cs220::assignments::assignment08::{from_usize, to_usize_checked};
+///
+/// assert_eq!(to_usize_checked(from_usize::<usize>(5)), Some(5));
+/// ```
+pub fn to_usize_checked<T: 'static + Default>(n: Church<T>) -> Option<usize> {
+    let count: Rc<RefCell<Option<usize>>> = Rc::new(RefCell::new(Some(0)));
+    let count_for_step = count.clone();
+    let inc: Rc<dyn Fn(T) -> T> = Rc::new(move |_| {
+        let mut count = count_for_step.borrow_mut();
+        *count = count.and_then(|c| c.checked_add(1));
+        T::default()
+    });
+    let _ = n(inc)(T::default());
+    *count.borrow()
+}
+
+/// A Church numeral paired with its value, tracked alongside the closure so that recovering the
+/// value is O(1) instead of O(n) applications of `f` as in [`to_usize_checked`].
+///
+/// [`CountedChurch::succ`], [`CountedChurch::add`], [`CountedChurch::mult`], and
+/// [`CountedChurch::pow`] all update the count via checked `u128` arithmetic alongside building
+/// the closure, so overflow is detected at the point it happens rather than silently propagating.
+/// A numeral built some other way (e.g. a hand-written [`Church<T>`] closure) can still be wrapped
+/// via [`CountedChurch::from_church`], which falls back to recovering the count by driving the
+/// closure once through [`to_usize_checked`].
+#[derive(Clone)]
+pub struct CountedChurch<T> {
+    /// The closure form, usable anywhere a plain [`Church<T>`] is expected.
+    pub church: Church<T>,
+    /// The numeral's value, or `None` if it overflowed `u128` while being computed.
+    pub count: Option<u128>,
+}
+
+impl<T: 'static> CountedChurch<T> {
+    /// Wraps the Church numeral for zero, with a known count of `0`.
+    pub fn zero() -> Self {
+        CountedChurch {
+            church: zero(),
+            count: Some(0),
+        }
+    }
+
+    /// Wraps the Church numeral for `n`, reusing [`from_usize`] for the closure form (which only
+    /// does `O(log n)` closure composition, not `n` applications of `f`) and setting the count
+    /// directly.
+    pub fn from_usize(n: usize) -> Self {
+        CountedChurch {
+            church: from_usize(n),
+            count: Some(n as u128),
+        }
+    }
+
+    /// Wraps an arbitrary closure-built numeral, recovering its count by driving it once through
+    /// [`to_usize_checked`] (an O(n) pass, unlike the O(1) operations above).
+    pub fn from_church(church: Church<T>) -> Self
+    where
+        T: Default,
+    {
+        let count = to_usize_checked(church.clone()).map(|n| n as u128);
+        CountedChurch { church, count }
+    }
+
+    /// Converts to a `usize` in O(1), or `None` if the tracked count doesn't fit in a `usize`
+    /// (including because some earlier operation already overflowed `u128`).
+    pub fn to_usize_checked(&self) -> Option<usize> {
+        self.count.and_then(|c| usize::try_from(c).ok())
+    }
+
+    /// Church-numeral successor, updating the count via checked `u128` addition.
+    pub fn succ(self) -> Self {
+        CountedChurch {
+            church: succ(self.church),
+            count: self.count.and_then(|c| c.checked_add(1)),
+        }
+    }
+
+    /// Church-numeral addition, updating the count via checked `u128` addition.
+    pub fn add(self, other: Self) -> Self {
+        let CountedChurch {
+            church: c1,
+            count: n1,
+        } = self;
+        let CountedChurch {
+            church: c2,
+            count: n2,
+        } = other;
+        CountedChurch {
+            church: add(c1, c2),
+            count: n1.and_then(|a| n2.and_then(|b| a.checked_add(b))),
+        }
+    }
+
+    /// Church-numeral multiplication, updating the count via checked `u128` multiplication.
+    pub fn mult(self, other: Self) -> Self {
+        let CountedChurch {
+            church: c1,
+            count: n1,
+        } = self;
+        let CountedChurch {
+            church: c2,
+            count: n2,
+        } = other;
+        CountedChurch {
+            church: mult(c1, c2),
+            count: n1.and_then(|a| n2.and_then(|b| a.checked_mul(b))),
+        }
+    }
+
+    /// Church-numeral exponentiation (`self` to the power `other`), updating the count via checked
+    /// `u128::checked_pow`.
+    ///
+    /// Builds the closure form via repeated [`mult`] (square-and-multiply over the exponent's
+    /// bits, driven by its `u128` count rather than applying the exponent numeral itself), so it
+    /// stays O(log m) Church-level multiplications instead of the O(n^m) nested applications the
+    /// free [`exp`] function would need to reach astronomically large results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cs220::assignments::assignment08::CountedChurch;
+    ///
+    /// // `exp(10, 10)` would apply `f` ten billion times, far too many to drive through a
+    /// // closure; the counted path recovers the same value in O(log 10) multiplications.
+    /// let base = CountedChurch::<()>::from_usize(10);
+    /// let exponent = CountedChurch::<()>::from_usize(10);
+    /// assert_eq!(base.pow(exponent).to_usize_checked(), Some(10_000_000_000));
+    ///
+    /// // Overflowing `u128` while computing the count is reported instead of panicking or
+    /// // silently wrapping.
+    /// let huge = CountedChurch::<()>::from_usize(usize::MAX);
+    /// assert_eq!(huge.clone().pow(huge).to_usize_checked(), None);
+    /// ```
+    pub fn pow(self, other: Self) -> Self {
+        let CountedChurch {
+            church: mut base,
+            count: base_count,
+        } = self;
+        let exponent = other.count.unwrap_or(0);
+        let count = base_count.and_then(|b| {
+            other
+                .count
+                .and_then(|e| u32::try_from(e).ok().and_then(|e32| b.checked_pow(e32)))
+        });
+
+        let mut result = one();
+        let mut remaining = exponent;
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result = mult(result, base.clone());
+            }
+            base = mult(base.clone(), base);
+            remaining >>= 1;
+        }
+
+        CountedChurch {
+            church: result,
+            count,
+        }
+    }
+}
+
 /// Implement a function to convert a Church numeral to a usize type.
 /* fixme: this implementation is too slow to pass the challenge test.
  *        I have no idea about fast convertion :(.
@@ -138,6 +138,246 @@ impl Sub for BigInt {
     }
 }
 
+impl BigInt {
+    /// Returns whether `self` is negative, per the sign bit of the most-significant limb.
+    fn is_negative(&self) -> bool {
+        self.carrier.first().unwrap() & SIGN_MASK != 0
+    }
+
+    /// Returns the non-negative magnitude of `self`, negating via [`two_complement`](Self::two_complement) if needed.
+    fn magnitude(&self) -> Self {
+        if self.is_negative() {
+            self.two_complement()
+        } else {
+            self.clone()
+        }
+    }
+}
+
+/// Schoolbook limb multiplication of two non-negative magnitudes, treating `carrier` as
+/// most-significant-first `u32` limbs. Each pairwise product `a[i] * b[j]` (itself at most
+/// `u64::MAX`, since both factors fit in `u32`) is added into a little-endian scratch buffer at
+/// limb position `i + j`, with the carry out of that addition propagated immediately via
+/// `overflowing_add` -- a `u64` slot's overflow is worth `2^64`, i.e. two limbs, so it lands two
+/// slots up -- unlike summing raw products into a shared slot before any carry propagation, which
+/// can overflow `u64` once more than a couple of products land on the same position. A final
+/// pass then folds the scratch buffer into a proper base-2^32 representation, adding each slot
+/// to the incoming carry in `u128` so that sum can't overflow either.
+fn mul_magnitude(a: &BigInt, b: &BigInt) -> BigInt {
+    let a_limbs: Vec<u64> = a.carrier.iter().rev().map(|&limb| limb as u64).collect();
+    let b_limbs: Vec<u64> = b.carrier.iter().rev().map(|&limb| limb as u64).collect();
+
+    let mut result = vec![0u64; a_limbs.len() + b_limbs.len()];
+    for (i, &ai) in a_limbs.iter().enumerate() {
+        for (j, &bj) in b_limbs.iter().enumerate() {
+            let product = ai * bj;
+
+            let (sum, mut carry) = result[i + j].overflowing_add(product);
+            result[i + j] = sum;
+
+            let mut k = i + j + 2;
+            while carry {
+                let (sum, overflow) = result[k].overflowing_add(1);
+                result[k] = sum;
+                carry = overflow;
+                k += 2;
+            }
+        }
+    }
+
+    let mut carry = 0u64;
+    let mut limbs = Vec::with_capacity(result.len() + 1);
+    for value in result {
+        let sum = value as u128 + carry as u128;
+        limbs.push(sum as u32);
+        carry = (sum >> 32) as u64;
+    }
+    while carry > 0 {
+        limbs.push(carry as u32);
+        carry >>= 32;
+    }
+    limbs.push(0); // keep the magnitude's sign bit clear
+    limbs.reverse();
+    BigInt::new_large(limbs).truncate()
+}
+
+/// Returns whether the non-negative magnitude `a` is greater than or equal to `b`.
+fn ge_magnitude(a: &BigInt, b: &BigInt) -> bool {
+    !(a.clone() - b.clone()).is_negative()
+}
+
+/// Iterates over the bits of `carrier`, most significant first.
+fn bits_msb_first(carrier: &[u32]) -> impl Iterator<Item = u32> + '_ {
+    carrier
+        .iter()
+        .flat_map(|limb| (0..32).rev().map(move |i| (limb >> i) & 1))
+}
+
+/// Long division of two non-negative magnitudes, producing `(quotient, remainder)`. Builds the
+/// quotient bit by bit, most significant first: at each step the remainder is doubled, the next
+/// bit of `a` is brought down, and `b` is subtracted out whenever it still fits.
+fn div_rem_magnitude(a: &BigInt, b: &BigInt) -> (BigInt, BigInt) {
+    let mut quotient_bits = Vec::with_capacity(a.carrier.len() * 32);
+    let mut remainder = BigInt::new(0);
+    for bit in bits_msb_first(&a.carrier) {
+        remainder = remainder.clone() + remainder.clone();
+        if bit == 1 {
+            remainder = remainder + BigInt::new(1);
+        }
+        if ge_magnitude(&remainder, b) {
+            remainder = remainder - b.clone();
+            quotient_bits.push(1);
+        } else {
+            quotient_bits.push(0);
+        }
+    }
+
+    let mut quotient_carrier: Vec<u32> = quotient_bits
+        .chunks(32)
+        .map(|chunk| chunk.iter().fold(0u32, |acc, &bit| (acc << 1) | bit))
+        .collect();
+    quotient_carrier.insert(0, 0); // keep the quotient's sign bit clear
+    let quotient = BigInt::new_large(quotient_carrier).truncate();
+
+    (quotient, remainder.truncate())
+}
+
+impl Mul for BigInt {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let negative = self.is_negative() != rhs.is_negative();
+        let magnitude = mul_magnitude(&self.magnitude(), &rhs.magnitude());
+        if negative {
+            magnitude.two_complement()
+        } else {
+            magnitude
+        }
+    }
+}
+
+impl BigInt {
+    /// Computes the quotient and remainder of dividing `self` by `rhs` via long division on their
+    /// magnitudes, then reapplies the sign: the quotient's sign is the XOR of the operand signs
+    /// (truncating division, matching Rust's built-in integer division), and the remainder keeps
+    /// the dividend's sign. Shared by [`Div`] and [`Rem`] so neither recomputes the other.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div_rem(self, rhs: Self) -> (Self, Self) {
+        assert!(rhs.truncate().carrier != vec![0], "division by zero");
+
+        let dividend_negative = self.is_negative();
+        let divisor_negative = rhs.is_negative();
+        let (quotient_mag, remainder_mag) = div_rem_magnitude(&self.magnitude(), &rhs.magnitude());
+
+        let quotient = if dividend_negative != divisor_negative {
+            quotient_mag.two_complement()
+        } else {
+            quotient_mag
+        };
+        let remainder = if dividend_negative {
+            remainder_mag.two_complement()
+        } else {
+            remainder_mag
+        };
+        (quotient, remainder)
+    }
+
+    /// Raises `self` to the power `exp` via binary (square-and-multiply) exponentiation, taking
+    /// `O(log exp)` multiplications instead of `exp` of them.
+    pub fn pow(self, exp: u64) -> Self {
+        let mut base = self;
+        let mut remaining = exp;
+        let mut result = BigInt::new(1);
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result = result * base.clone();
+            }
+            base = base.clone() * base;
+            remaining >>= 1;
+        }
+        result
+    }
+
+    /// Parses `s` as a `BigInt` in the given `radix`, accepting an optional leading `-`. Folds the
+    /// digits most-significant first (`acc = acc * radix + digit`), then negates the result via
+    /// `two_complement` if a minus sign was present. (We assume every character of `s` other than
+    /// a possible leading `-` is a valid digit for `radix`.)
+    pub fn from_str_radix(s: &str, radix: u32) -> Self {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+
+        let radix_big = BigInt::new(radix);
+        let mut value = BigInt::new(0);
+        for c in digits.chars() {
+            let digit = c.to_digit(radix).expect("invalid digit for radix");
+            value = value * radix_big.clone() + BigInt::new(digit);
+        }
+
+        if negative {
+            value.two_complement()
+        } else {
+            value
+        }
+    }
+
+    /// Converts the magnitude of `self` to a most-significant-first digit sequence in `radix`, by
+    /// repeatedly dividing by `radix` and collecting remainders (then reversing). Zero yields
+    /// `vec![0]`; the sign is dropped, matching [`to_string_radix`](Self::to_string_radix), which
+    /// recovers it separately via [`is_negative`](Self::is_negative).
+    pub fn to_digits(&self, radix: u32) -> Vec<u8> {
+        let radix_big = BigInt::new(radix);
+        let mut value = self.magnitude();
+        let mut digits = Vec::new();
+
+        loop {
+            let (quotient, remainder) = value.div_rem(radix_big.clone());
+            digits.push(remainder.carrier.last().copied().unwrap_or(0) as u8);
+            let quotient_is_zero = quotient.truncate().carrier == vec![0];
+            value = quotient;
+            if quotient_is_zero {
+                break;
+            }
+        }
+
+        digits.reverse();
+        digits
+    }
+
+    /// Formats `self` in `radix` as a sign-prefixed digit string (`0`-`9` then `a`-`z` for radix
+    /// up to 36), via [`to_digits`](Self::to_digits).
+    pub fn to_string_radix(&self, radix: u32) -> String {
+        let mut result = String::new();
+        if self.is_negative() {
+            result.push('-');
+        }
+        for digit in self.to_digits(radix) {
+            result.push(char::from_digit(digit as u32, radix).expect("digit must fit radix"));
+        }
+        result
+    }
+}
+
+impl Div for BigInt {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem for BigInt {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
 impl fmt::Display for BigInt {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // Hex formatting so that each u32 can be formatted independently.
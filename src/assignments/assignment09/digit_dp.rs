@@ -0,0 +1,218 @@
+//! Digit DP: counting integers in a range that satisfy a property of their digits, driven by a
+//! finite automaton over the digit sequence.
+//!
+//! The DP recurses over `(position, state)`, where `state` is wherever the automaton has gotten to
+//! after reading the digits seen so far, and is memoized on that pair — so a property like "digits
+//! sum to a multiple of k" or "at most N" can be counted in time proportional to
+//! `digits * reachable states`, not by enumerating every integer in the range.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::bigint::BigInt;
+
+/// A finite automaton over a digit sequence, used to drive the digit-DP count in [`count`].
+pub trait Automaton {
+    /// The automaton's state type.
+    type State: Clone + Eq + Hash;
+
+    /// The automaton's initial state, before any digits have been read.
+    fn init(&self) -> Self::State;
+
+    /// Advances `state` by reading `digit`, or returns `None` if no transition applies, rejecting
+    /// the digit sequence read so far regardless of any digits still to come.
+    fn step(&self, state: &Self::State, digit: u8) -> Option<Self::State>;
+
+    /// Returns whether `state` is accepting once the whole digit sequence has been read.
+    fn accept(&self, state: &Self::State) -> bool;
+}
+
+/// Product automaton combining `A` and `B`: runs both in lockstep over the same digit sequence and
+/// accepts only when both do. Built via [`intersect`].
+pub struct Product<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A: Automaton, B: Automaton> Automaton for Product<A, B> {
+    type State = (A::State, B::State);
+
+    fn init(&self) -> Self::State {
+        (self.first.init(), self.second.init())
+    }
+
+    fn step(&self, state: &Self::State, digit: u8) -> Option<Self::State> {
+        let first = self.first.step(&state.0, digit)?;
+        let second = self.second.step(&state.1, digit)?;
+        Some((first, second))
+    }
+
+    fn accept(&self, state: &Self::State) -> bool {
+        self.first.accept(&state.0) && self.second.accept(&state.1)
+    }
+}
+
+/// Intersects two automatons into their product, accepting a digit sequence iff both `a` and `b`
+/// accept it.
+pub fn intersect<A: Automaton, B: Automaton>(a: A, b: B) -> Product<A, B> {
+    Product {
+        first: a,
+        second: b,
+    }
+}
+
+/// Whether the digit prefix read so far is already strictly less than `N`'s prefix at this
+/// position, or still tied with it (tracking how many digits have been read).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LeqState {
+    /// Some earlier digit was strictly less than `N`'s, so every completion is `<= N`.
+    Less,
+    /// Every digit read so far equals `N`'s, at this position.
+    Tied(usize),
+}
+
+/// Automaton accepting exactly the digit sequences, of the same length as `N`'s
+/// most-significant-first digits, that represent an integer `<= N`.
+pub struct Leq {
+    digits: Vec<u8>,
+}
+
+impl Leq {
+    /// Builds the "`<= N`" automaton from `N`'s most-significant-first digits (see
+    /// [`BigInt::to_digits`]).
+    pub fn new(digits: Vec<u8>) -> Self {
+        Leq { digits }
+    }
+}
+
+impl Automaton for Leq {
+    type State = LeqState;
+
+    fn init(&self) -> Self::State {
+        LeqState::Tied(0)
+    }
+
+    fn step(&self, state: &Self::State, digit: u8) -> Option<Self::State> {
+        match *state {
+            LeqState::Less => Some(LeqState::Less),
+            LeqState::Tied(position) => {
+                let bound = *self.digits.get(position)?;
+                match digit.cmp(&bound) {
+                    std::cmp::Ordering::Less => Some(LeqState::Less),
+                    std::cmp::Ordering::Equal => Some(LeqState::Tied(position + 1)),
+                    std::cmp::Ordering::Greater => None,
+                }
+            }
+        }
+    }
+
+    fn accept(&self, _state: &Self::State) -> bool {
+        true
+    }
+}
+
+/// Automaton accepting digit sequences whose digits sum to a multiple of `k`, e.g. for counting
+/// numbers divisible by 3 in base 10.
+pub struct DigitSumMod {
+    k: u64,
+}
+
+impl DigitSumMod {
+    /// Builds the "digit sum is a multiple of `k`" automaton.
+    pub fn new(k: u64) -> Self {
+        DigitSumMod { k }
+    }
+}
+
+impl Automaton for DigitSumMod {
+    type State = u64;
+
+    fn init(&self) -> Self::State {
+        0
+    }
+
+    fn step(&self, state: &Self::State, digit: u8) -> Option<Self::State> {
+        Some((state + digit as u64) % self.k)
+    }
+
+    fn accept(&self, state: &Self::State) -> bool {
+        *state == 0
+    }
+}
+
+/// Counts how many digit sequences of length `len` over `radix` lead `automaton` from its initial
+/// state to an accepting state, memoizing on `(position, state)` so each reachable state is
+/// explored at most once per position.
+pub fn count<A: Automaton>(automaton: &A, len: usize, radix: u8) -> BigInt {
+    let mut memo = HashMap::new();
+    count_from(automaton, automaton.init(), 0, len, radix, &mut memo)
+}
+
+fn count_from<A: Automaton>(
+    automaton: &A,
+    state: A::State,
+    position: usize,
+    len: usize,
+    radix: u8,
+    memo: &mut HashMap<(usize, A::State), BigInt>,
+) -> BigInt {
+    if position == len {
+        return if automaton.accept(&state) {
+            BigInt::new(1)
+        } else {
+            BigInt::new(0)
+        };
+    }
+    if let Some(cached) = memo.get(&(position, state.clone())) {
+        return cached.clone();
+    }
+
+    let mut total = BigInt::new(0);
+    for digit in 0..radix {
+        if let Some(next) = automaton.step(&state, digit) {
+            total = total + count_from(automaton, next, position + 1, len, radix, memo);
+        }
+    }
+
+    let _ = memo.insert((position, state), total.clone());
+    total
+}
+
+/// Counts how many integers in `[0, N]` are accepted by `automaton`, where `n_digits` is `N`'s
+/// most-significant-first digits (see [`BigInt::to_digits`]). Built as the product of `automaton`
+/// with the [`Leq`] automaton for `N`, so e.g. `count_leq_with(n_digits, 10, DigitSumMod::new(3))`
+/// counts the numbers in `[0, N]` divisible by 3.
+///
+/// # Examples
+///
+/// ```
+/// use cs220::assignments::assignment09::bigint::BigInt;
+/// use cs220::assignments::assignment09::digit_dp::{count_leq_with, DigitSumMod};
+///
+/// // How many integers in [0, 20] have a digit sum that's a multiple of 3?
+/// // (0, 3, 6, 9, 12, 15, 18 -- seven of them.)
+/// let n_digits = BigInt::new(20).to_digits(10);
+/// let count = count_leq_with(&n_digits, 10, DigitSumMod::new(3));
+/// assert_eq!(count.to_string_radix(10), "7");
+/// ```
+pub fn count_leq_with<A: Automaton>(n_digits: &[u8], radix: u8, automaton: A) -> BigInt {
+    let product = intersect(Leq::new(n_digits.to_vec()), automaton);
+    count(&product, n_digits.len(), radix)
+}
+
+/// Counts how many integers there are in `[0, N]`, where `n_digits` is `N`'s
+/// most-significant-first digits (see [`BigInt::to_digits`]) -- i.e. just `N + 1`, but computed
+/// through the same digit-DP machinery as a sanity check for [`Leq`].
+///
+/// # Examples
+///
+/// ```
+/// use cs220::assignments::assignment09::bigint::BigInt;
+/// use cs220::assignments::assignment09::digit_dp::count_leq;
+///
+/// let n_digits = BigInt::new(20).to_digits(10);
+/// assert_eq!(count_leq(&n_digits, 10).to_string_radix(10), "21");
+/// ```
+pub fn count_leq(n_digits: &[u8], radix: u8) -> BigInt {
+    count(&Leq::new(n_digits.to_vec()), n_digits.len(), radix)
+}
@@ -30,6 +30,45 @@ pub fn inversion<T: Ord>(inner: Vec<T>) -> Vec<(usize, usize)> {
         .collect()
 }
 
+/// Counts the number of inversions in `inner` in `O(n log n)` via merge sort, for callers who
+/// only need the count (rather than [`inversion`]'s full pair list) on large inputs.
+///
+/// `inner` is not modified; the algorithm works over a cloned buffer.
+pub fn count_inversions<T: Ord + Clone>(inner: &[T]) -> usize {
+    fn sort_and_count<T: Ord + Clone>(values: &mut [T]) -> usize {
+        let len = values.len();
+        if len <= 1 {
+            return 0;
+        }
+
+        let mid = len / 2;
+        let left_count = sort_and_count(&mut values[..mid]);
+        let right_count = sort_and_count(&mut values[mid..]);
+
+        let mut merged = Vec::with_capacity(len);
+        let mut cross_count = 0;
+        let (mut i, mut j) = (0, mid);
+        while i < mid && j < len {
+            if values[i] <= values[j] {
+                merged.push(values[i].clone());
+                i += 1;
+            } else {
+                merged.push(values[j].clone());
+                j += 1;
+                cross_count += mid - i;
+            }
+        }
+        merged.extend_from_slice(&values[i..mid]);
+        merged.extend_from_slice(&values[j..len]);
+        values.clone_from_slice(&merged);
+
+        left_count + right_count + cross_count
+    }
+
+    let mut working = inner.to_vec();
+    sort_and_count(&mut working)
+}
+
 /// Represents a node of tree data structure.
 ///
 /// Consult <https://en.wikipedia.org/wiki/Tree_(data_structure)> for more details on tree data structure.
@@ -106,6 +145,63 @@ pub enum File {
     Data(String, usize),
 }
 
+impl File {
+    /// Returns the file's name.
+    fn name(&self) -> &str {
+        match self {
+            File::Directory(name, _) => name,
+            File::Data(name, _) => name,
+        }
+    }
+
+    /// Computes the size of every file in the tree in a single bottom-up pass, keyed by name
+    /// (assuming no duplicate file names), rather than recomputing a directory's size from
+    /// scratch at every level.
+    fn sizes(&self) -> HashMap<&str, usize> {
+        let mut sizes = HashMap::new();
+        self.collect_sizes(&mut sizes);
+        sizes
+    }
+
+    fn collect_sizes<'a>(&'a self, sizes: &mut HashMap<&'a str, usize>) -> usize {
+        let size = match self {
+            File::Data(_, size) => *size,
+            File::Directory(_, subfiles) => subfiles.iter().map(|file| file.collect_sizes(sizes)).sum(),
+        };
+        _ = sizes.insert(self.name(), size);
+        size
+    }
+
+    /// Streams the file tree in preorder as `(depth, node)` pairs, using an explicit stack rather
+    /// than recursion, so callers can stop early or filter without materializing the whole tree.
+    ///
+    /// Children are pushed in reverse so they pop, and therefore yield, left to right.
+    pub fn walk(&self) -> impl Iterator<Item = (usize, &File)> {
+        Walk { stack: vec![(0, self)] }
+    }
+}
+
+/// Lazy preorder iterator over a [`File`] tree, returned by [`File::walk`].
+struct Walk<'a> {
+    stack: Vec<(usize, &'a File)>,
+}
+
+impl<'a> Iterator for Walk<'a> {
+    type Item = (usize, &'a File);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (depth, file) = self.stack.pop()?;
+
+        if let File::Directory(_, subfiles) = file {
+            for subfile in subfiles.iter().rev() {
+                self.stack.push((depth + 1, subfile));
+            }
+        }
+
+        Some((depth, file))
+    }
+}
+
 /// Given a file, summarize all subfiles and sizes in ascending order of size.
 ///
 /// - Its behaviour is the same as the `du | sort -h` command on Linux.
@@ -132,31 +228,8 @@ pub enum File {
 ///
 /// Output: `[("a1", 1), ("a2", 3), ("b1", 3), ("a", 4), ("c", 8), ("b2", 15), ("b", 18), ("root", 30)]`
 pub fn du_sort(root: &File) -> Vec<(&str, usize)> {
-    fn compute_size(root: &File) -> usize {
-        match root {
-            File::Data(data, size) => *size,
-            File::Directory(dir, subfile) => subfile.iter().map(compute_size).sum(),
-        }
-    }
-    let mut result = match root {
-        File::Directory(dirname, subfiles) => {
-            let mut result = vec![(dirname.as_str(), compute_size(root))];
-            for file in subfiles {
-                match file {
-                    File::Directory(dir, files) => {
-                        result.append(&mut du_sort(file));
-                    }
-                    File::Data(name, size) => {
-                        result.push((name, *size));
-                    }
-                }
-            }
-            result
-        }
-        File::Data(name, size) => {
-            vec![(name.as_str(), *size)]
-        }
-    };
+    let sizes = root.sizes();
+    let mut result: Vec<(&str, usize)> = root.walk().map(|(_, file)| (file.name(), sizes[file.name()])).collect();
     result.sort_by(|a, b| {
         if a.1 == b.1 {
             a.0.cmp(b.0)
@@ -167,6 +240,25 @@ pub fn du_sort(root: &File) -> Vec<(&str, usize)> {
     result
 }
 
+/// Like [`du_sort`], but groups entries by depth in the tree (root at depth `0`), sorting each
+/// group the same way `du_sort` sorts the whole tree.
+pub fn du_sort_by_depth(root: &File) -> Vec<Vec<(&str, usize)>> {
+    let sizes = root.sizes();
+    let mut by_depth: Vec<Vec<(&str, usize)>> = Vec::new();
+    for (depth, file) in root.walk() {
+        if depth >= by_depth.len() {
+            by_depth.resize(depth + 1, Vec::new());
+        }
+        by_depth[depth].push((file.name(), sizes[file.name()]));
+    }
+
+    for group in &mut by_depth {
+        group.sort_by(|a, b| if a.1 == b.1 { a.0.cmp(b.0) } else { a.1.cmp(&b.1) });
+    }
+
+    by_depth
+}
+
 /// Remove all even numbers inside a vector using the given mutable reference.
 /// That is, you must modify the vector using the given mutable reference instead
 /// of returning a new vector.
@@ -0,0 +1,375 @@
+//! A write-optimized key -> size index (a Bε-tree), the design used by write-optimized on-disk
+//! stores like TokuDB/BetrFS. Unlike a plain B-tree, every internal node carries a bounded
+//! *message buffer*: an upsert or delete is applied by appending a message to the root's buffer in
+//! O(1) amortized time, rather than paying a full root-to-leaf descent on every write. Buffers are
+//! only flushed -- pushed one level down, toward the leaves -- once they grow past a capacity `ε`,
+//! batching many logical writes into each descent.
+
+use std::collections::{BTreeMap, HashMap};
+
+/// Default message-buffer capacity (`ε`) before an internal node flushes.
+const DEFAULT_EPSILON: usize = 4;
+
+/// Default fanout (`B`): the maximum number of pivots (and leaf entries) before a node splits.
+const DEFAULT_FANOUT: usize = 4;
+
+/// A pending write, buffered at some internal node on its way down to a leaf.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Message {
+    /// Set the key's size, inserting or overwriting it.
+    Upsert(usize),
+    /// Remove the key.
+    Delete,
+}
+
+/// Applies `message` to `value`, where `value` is `None` when the key isn't currently known.
+fn apply_to_option(value: &mut Option<usize>, message: Message) {
+    match message {
+        Message::Upsert(size) => *value = Some(size),
+        Message::Delete => *value = None,
+    }
+}
+
+/// Applies `message` directly to a leaf's entries.
+fn apply_to_leaf(entries: &mut BTreeMap<String, usize>, key: String, message: Message) {
+    match message {
+        Message::Upsert(size) => {
+            let _ = entries.insert(key, size);
+        }
+        Message::Delete => {
+            let _ = entries.remove(&key);
+        }
+    }
+}
+
+/// Returns the index of the child `key` belongs under, given a node's pivots (`children.len() ==
+/// pivots.len() + 1`; `pivots[i]` is the smallest key reachable through `children[i + 1]`).
+fn child_index(pivots: &[String], key: &str) -> usize {
+    pivots.partition_point(|pivot| pivot.as_str() <= key)
+}
+
+/// A node of the tree. Recursive, so internal nodes box their children.
+#[derive(Debug)]
+enum Node {
+    /// A leaf, holding actual key/size entries.
+    Leaf { entries: BTreeMap<String, usize> },
+    /// An internal node: a pivot array routing to children, plus a buffer of messages not yet
+    /// pushed down to them.
+    Internal {
+        pivots: Vec<String>,
+        children: Vec<Box<Node>>,
+        buffer: Vec<(String, Message)>,
+    },
+}
+
+/// Pushes `messages` into `node`, applying them if `node` is a leaf or buffering (and possibly
+/// flushing) them if it's internal. Returns `Some((pivot, right))` if `node` split as a result, for
+/// the caller to link into its own pivot/child arrays (or, at the root, to wrap in a new root).
+fn push_messages(
+    node: &mut Node,
+    messages: Vec<(String, Message)>,
+    epsilon: usize,
+    fanout: usize,
+) -> Option<(String, Node)> {
+    match node {
+        Node::Leaf { entries } => {
+            for (key, message) in messages {
+                apply_to_leaf(entries, key, message);
+            }
+            split_leaf_if_needed(entries, fanout)
+        }
+        Node::Internal { buffer, .. } => {
+            buffer.extend(messages);
+            flush_internal_if_needed(node, epsilon, fanout)
+        }
+    }
+}
+
+/// Splits `entries` in half by key order if it's grown past `fanout`, returning the promoted pivot
+/// and the new right-hand leaf.
+fn split_leaf_if_needed(entries: &mut BTreeMap<String, usize>, fanout: usize) -> Option<(String, Node)> {
+    if entries.len() <= fanout {
+        return None;
+    }
+
+    let mid = entries.len() / 2;
+    let right_keys: Vec<String> = entries.keys().skip(mid).cloned().collect();
+    let mut right_entries = BTreeMap::new();
+    for key in &right_keys {
+        if let Some(value) = entries.remove(key) {
+            let _ = right_entries.insert(key.clone(), value);
+        }
+    }
+    let pivot = right_keys[0].clone();
+    Some((pivot, Node::Leaf { entries: right_entries }))
+}
+
+/// Flushes `node`'s buffer if it's grown past `epsilon`: groups the buffered messages by which
+/// child they belong to, and pushes only the *largest* group down, recursing into that child alone
+/// (the rest stay buffered here until the next flush). Then splits `node` if it now has too many
+/// pivots.
+fn flush_internal_if_needed(node: &mut Node, epsilon: usize, fanout: usize) -> Option<(String, Node)> {
+    let Node::Internal {
+        pivots,
+        children,
+        buffer,
+    } = node
+    else {
+        unreachable!("only called on internal nodes")
+    };
+
+    if buffer.len() > epsilon {
+        let mut groups: HashMap<usize, Vec<(String, Message)>> = HashMap::new();
+        for (key, message) in buffer.drain(..) {
+            let index = child_index(pivots, &key);
+            groups.entry(index).or_default().push((key, message));
+        }
+
+        if let Some(&target) = groups.keys().max_by_key(|index| groups[index].len()) {
+            let largest = groups.remove(&target).expect("just looked up `target` from this map");
+            for messages in groups.into_values() {
+                buffer.extend(messages);
+            }
+
+            if let Some((pivot, right)) = push_messages(&mut children[target], largest, epsilon, fanout) {
+                pivots.insert(target, pivot);
+                children.insert(target + 1, Box::new(right));
+            }
+        }
+    }
+
+    if pivots.len() <= fanout {
+        return None;
+    }
+
+    let mid = pivots.len() / 2;
+    let split_pivot = pivots[mid].clone();
+    let right_pivots = pivots.split_off(mid + 1);
+    let _ = pivots.pop(); // the promoted pivot moves up, not into either side
+    let right_children = children.split_off(mid + 1);
+
+    let mut left_buffer = Vec::new();
+    let mut right_buffer = Vec::new();
+    for (key, message) in buffer.drain(..) {
+        if key < split_pivot {
+            left_buffer.push((key, message));
+        } else {
+            right_buffer.push((key, message));
+        }
+    }
+    *buffer = left_buffer;
+
+    Some((
+        split_pivot,
+        Node::Internal {
+            pivots: right_pivots,
+            children: right_children,
+            buffer: right_buffer,
+        },
+    ))
+}
+
+/// Recursively materializes every key/size entry reachable from `node` into `out`, applying
+/// buffered messages on top of whatever their subtree already contributed (root buffers are the
+/// most recently written, so they're folded in last).
+fn collect_into(node: &Node, out: &mut HashMap<String, usize>) {
+    match node {
+        Node::Leaf { entries } => {
+            for (key, value) in entries {
+                let _ = out.insert(key.clone(), *value);
+            }
+        }
+        Node::Internal {
+            children, buffer, ..
+        } => {
+            for child in children {
+                collect_into(child, out);
+            }
+            for (key, message) in buffer {
+                match message {
+                    Message::Upsert(size) => {
+                        let _ = out.insert(key.clone(), *size);
+                    }
+                    Message::Delete => {
+                        let _ = out.remove(key);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A write-optimized key -> size index, usable as an amortized-cost backing store for
+/// [`MockStorage`](super::mock_storage::MockStorage)'s bulk uploads.
+#[derive(Debug)]
+pub struct BetaTree {
+    root: Node,
+    epsilon: usize,
+    fanout: usize,
+}
+
+impl Default for BetaTree {
+    fn default() -> Self {
+        Self::new(DEFAULT_EPSILON, DEFAULT_FANOUT)
+    }
+}
+
+impl BetaTree {
+    /// Creates an empty tree with the given message-buffer capacity (`epsilon`) and fanout (`B`).
+    pub fn new(epsilon: usize, fanout: usize) -> Self {
+        Self {
+            root: Node::Leaf {
+                entries: BTreeMap::new(),
+            },
+            epsilon,
+            fanout,
+        }
+    }
+
+    /// Upserts `name`'s size, appending a message to the root rather than descending to a leaf.
+    pub fn insert(&mut self, name: &str, size: usize) {
+        self.apply(name.to_string(), Message::Upsert(size));
+    }
+
+    /// Deletes `name`, appending a message to the root rather than descending to a leaf.
+    pub fn remove(&mut self, name: &str) {
+        self.apply(name.to_string(), Message::Delete);
+    }
+
+    /// Looks up `name`'s current size, folding any buffered messages along the root-to-leaf path
+    /// on top of the leaf's stored value so pending writes are visible.
+    pub fn get(&self, name: &str) -> Option<usize> {
+        let mut path_messages = Vec::new();
+        let mut node = &self.root;
+
+        loop {
+            match node {
+                Node::Leaf { entries } => {
+                    let mut value = entries.get(name).copied();
+                    for message in path_messages.into_iter().rev() {
+                        apply_to_option(&mut value, message);
+                    }
+                    return value;
+                }
+                Node::Internal {
+                    pivots,
+                    children,
+                    buffer,
+                } => {
+                    if let Some((_, message)) = buffer.iter().rev().find(|(key, _)| key == name) {
+                        path_messages.push(*message);
+                    }
+                    node = &children[child_index(pivots, name)];
+                }
+            }
+        }
+    }
+
+    /// Returns the sum of every currently-live key's size.
+    pub fn total_size(&self) -> usize {
+        self.materialize().values().sum()
+    }
+
+    /// Returns every currently-live key, in no particular order.
+    pub fn keys(&self) -> Vec<String> {
+        self.materialize().into_keys().collect()
+    }
+
+    /// Materializes the tree's current logical state (leaves plus every buffered write along the
+    /// way) into a plain map.
+    fn materialize(&self) -> HashMap<String, usize> {
+        let mut entries = HashMap::new();
+        collect_into(&self.root, &mut entries);
+        entries
+    }
+
+    /// Appends `message` for `key`, splitting the root if it overflows.
+    fn apply(&mut self, key: String, message: Message) {
+        if let Some((pivot, right)) = push_messages(&mut self.root, vec![(key, message)], self.epsilon, self.fanout) {
+            let old_root = std::mem::replace(&mut self.root, Node::Leaf { entries: BTreeMap::new() });
+            self.root = Node::Internal {
+                pivots: vec![pivot],
+                children: vec![Box::new(old_root), Box::new(right)],
+                buffer: Vec::new(),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap as StdHashMap;
+
+    /// A tiny deterministic xorshift generator, so the randomized test below is reproducible
+    /// without pulling in an external `rand` dependency.
+    struct Xorshift(u64);
+
+    impl Xorshift {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, bound: u64) -> u64 {
+            self.next() % bound
+        }
+    }
+
+    #[test]
+    fn matches_hash_map_oracle_under_random_ops() {
+        let mut tree = BetaTree::new(3, 3);
+        let mut oracle: StdHashMap<String, usize> = StdHashMap::new();
+        let mut rng = Xorshift(0x2545_f491_4f6c_dd1d);
+
+        const KEY_SPACE: u64 = 12;
+
+        for _ in 0..2000 {
+            let key = format!("key{}", rng.below(KEY_SPACE));
+            if rng.below(4) == 0 {
+                tree.remove(&key);
+                let _ = oracle.remove(&key);
+            } else {
+                let size = rng.below(1000) as usize;
+                tree.insert(&key, size);
+                let _ = oracle.insert(key, size);
+            }
+        }
+
+        for i in 0..KEY_SPACE {
+            let key = format!("key{i}");
+            assert_eq!(tree.get(&key), oracle.get(&key).copied(), "mismatch for {key}");
+        }
+
+        let expected_total: usize = oracle.values().sum();
+        assert_eq!(tree.total_size(), expected_total);
+
+        let mut tree_keys = tree.keys();
+        tree_keys.sort();
+        let mut oracle_keys: Vec<String> = oracle.keys().cloned().collect();
+        oracle_keys.sort();
+        assert_eq!(tree_keys, oracle_keys);
+    }
+
+    #[test]
+    fn splits_and_flushes_still_see_every_write() {
+        let mut tree = BetaTree::new(2, 2);
+        for i in 0..50 {
+            tree.insert(&format!("file-{i}"), i);
+        }
+        for i in (0..50).step_by(3) {
+            tree.remove(&format!("file-{i}"));
+        }
+
+        for i in 0..50 {
+            let key = format!("file-{i}");
+            let expected = if i % 3 == 0 { None } else { Some(i) };
+            assert_eq!(tree.get(&key), expected, "mismatch for {key}");
+        }
+
+        let expected_total: usize = (0..50).filter(|i| i % 3 != 0).sum();
+        assert_eq!(tree.total_size(), expected_total);
+    }
+}
@@ -0,0 +1,78 @@
+//! An encrypting [`Storage`] decorator, modeled on streaming-cipher backup tools (e.g. those built
+//! on ChaCha20) that wrap an inner sink and encrypt every file before writing it.
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+use super::mock_storage::Storage;
+
+/// Per-file nonce/AEAD-tag overhead charged on top of each file's plaintext size, modeling the
+/// fixed framing a streaming cipher prepends to every encrypted file.
+pub const HEADER_BYTES: usize = 24;
+
+/// A [`Storage`] decorator that charges the inner storage for each file's *ciphertext* size
+/// (`size + HEADER_BYTES`) instead of its plaintext size, so capacity accounting matches what an
+/// encrypted-at-rest backend would really consume.
+///
+/// Encrypting with a fresh per-file nonce means identical plaintext no longer produces identical
+/// ciphertext, so [`upload_content`](Storage::upload_content) can't meaningfully deduplicate once
+/// wrapped here: it falls back to charging every upload independently, same as
+/// [`upload`](Storage::upload).
+#[derive(Debug)]
+pub struct EncryptedStorage<T: Storage> {
+    inner: T,
+
+    /// Names currently stored, so [`overhead_bytes`](Self::overhead_bytes) can report the total
+    /// framing overhead without the inner storage needing to know about it.
+    names: RefCell<HashSet<String>>,
+}
+
+impl<T: Storage> EncryptedStorage<T> {
+    /// Wraps `inner` so every upload through this decorator is charged for its ciphertext size.
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            names: RefCell::new(HashSet::new()),
+        }
+    }
+
+    /// Returns the total framing overhead, in bytes, charged across all files currently stored
+    /// through this decorator.
+    pub fn overhead_bytes(&self) -> usize {
+        self.names.borrow().len() * HEADER_BYTES
+    }
+}
+
+impl<T: Storage> Storage for EncryptedStorage<T> {
+    fn upload(&self, name: &str, size: usize) -> Result<(), usize> {
+        self.inner.upload(name, size + HEADER_BYTES)?;
+        let _ = self.names.borrow_mut().insert(name.to_string());
+        Ok(())
+    }
+
+    fn upload_content(&self, name: &str, content: &[u8]) -> Result<(), usize> {
+        self.inner.upload(name, content.len() + HEADER_BYTES)?;
+        let _ = self.names.borrow_mut().insert(name.to_string());
+        Ok(())
+    }
+
+    fn remove(&self, name: &str) -> bool {
+        let removed = self.inner.remove(name);
+        if removed {
+            let _ = self.names.borrow_mut().remove(name);
+        }
+        removed
+    }
+
+    fn used(&self) -> usize {
+        self.inner.used()
+    }
+
+    fn logical_used(&self) -> usize {
+        self.inner.logical_used()
+    }
+
+    fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+}
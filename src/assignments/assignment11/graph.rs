@@ -15,7 +15,7 @@
 use std::{
     borrow::Borrow,
     cell::{Ref, RefCell},
-    collections::{HashMap, HashSet},
+    collections::{BinaryHeap, HashMap, HashSet},
     hash::Hash,
     rc::Rc,
 };
@@ -31,6 +31,7 @@ enum VisitStatus {
 pub struct Node {
     id: i32,
     adjacent: HashSet<i32>,
+    weights: HashMap<i32, i64>,
 }
 /// new
 impl Node {
@@ -39,6 +40,7 @@ impl Node {
         Node {
             id: value,
             adjacent: HashSet::new(),
+            weights: HashMap::new(),
         }
     }
 }
@@ -90,15 +92,32 @@ impl NodeHandle {
         }
     }
 
+    /// Adds a weighted edge to `to`, recording `w` alongside the existing unweighted adjacency.
+    /// If the modification cannot be done, e.g. because of aliasing issues, returns `Err(GraphError)`.
+    /// Returns `Ok(true)` if the edge is successfully added.
+    /// Returns `Ok(false)` if an edge to `to` already exists.
+    pub fn add_weighted_edge(&self, to: NodeHandle, w: i64) -> Result<bool, GraphError> {
+        let to_id = (*to.node).borrow().id;
+        let mut node = self.node.borrow_mut();
+        if node.adjacent.contains(&to_id) {
+            Ok(false)
+        } else {
+            _ = node.adjacent.insert(to_id);
+            _ = node.weights.insert(to_id, w);
+            Ok(true)
+        }
+    }
+
     /// Removes the edge to `to`.
     /// If the modification cannot be done, e.g. because of aliasing issues, returns `Err(GraphError)`.
     /// Returns `Ok(true)` if the edge is successfully removed.
     /// Returns `Ok(false)` if an edge to `to` does not exist.
     pub fn remove_edge(&self, to: &NodeHandle) -> Result<bool, GraphError> {
         let to_id = (*to.node).borrow().id;
-        let mut adjacent = &mut self.node.borrow_mut().adjacent;
-        if adjacent.contains(&to_id) {
-            _ = (*adjacent).remove(&to_id);
+        let mut node = self.node.borrow_mut();
+        if node.adjacent.contains(&to_id) {
+            _ = node.adjacent.remove(&to_id);
+            _ = node.weights.remove(&to_id);
             Ok(true)
         } else {
             Ok(false)
@@ -108,7 +127,9 @@ impl NodeHandle {
     /// Removes all edges.
     /// If the modification cannot be done, e.g. because of aliasing issues, returns `Err(GraphError)`.
     pub fn clear_edges(&self) -> Result<(), GraphError> {
-        self.node.borrow_mut().adjacent.clear();
+        let mut node = self.node.borrow_mut();
+        node.adjacent.clear();
+        node.weights.clear();
         Ok(())
     }
 }
@@ -197,4 +218,236 @@ impl SubGraph {
 
         false
     }
+
+    /// Returns a topological order of the subgraph's nodes, i.e. every edge `u -> v` has `u`
+    /// before `v` in the result, restricted to nodes in this subgraph (edges to nodes outside the
+    /// subgraph are ignored, exactly like [`SubGraph::detect_cycle`]).
+    ///
+    /// Uses Kahn's algorithm: <https://en.wikipedia.org/wiki/Topological_sorting#Kahn's_algorithm>.
+    ///
+    /// Returns `Err(GraphError)` if the subgraph contains a cycle.
+    pub fn topological_sort(&self) -> Result<Vec<i32>, GraphError> {
+        let mut in_degree: HashMap<i32, usize> = self.handles.keys().map(|&id| (id, 0)).collect();
+        for handle in self.handles.values() {
+            for &adj_id in (*handle.node).borrow().adjacent.iter() {
+                if let Some(degree) = in_degree.get_mut(&adj_id) {
+                    *degree += 1;
+                }
+            }
+        }
+
+        let mut queue: Vec<i32> = in_degree
+            .iter()
+            .filter(|&(_, &degree)| degree == 0)
+            .map(|(&id, _)| id)
+            .collect();
+        queue.sort_unstable();
+
+        let mut order = Vec::new();
+        let mut index = 0;
+        while index < queue.len() {
+            let id = queue[index];
+            index += 1;
+            order.push(id);
+
+            if let Some(handle) = self.handles.get(&id) {
+                for &adj_id in (*handle.node).borrow().adjacent.iter() {
+                    if let Some(degree) = in_degree.get_mut(&adj_id) {
+                        *degree -= 1;
+                        if *degree == 0 {
+                            queue.push(adj_id);
+                        }
+                    }
+                }
+            }
+        }
+
+        if order.len() == self.handles.len() {
+            Ok(order)
+        } else {
+            Err(GraphError)
+        }
+    }
+
+    /// Lazily yields every node in the subgraph that can reach one of `start`'s nodes via
+    /// directed edges, each exactly once. The ids come out in strictly decreasing order when every
+    /// edge points from a smaller id to a larger one (as in a revision DAG); otherwise a
+    /// predecessor with a larger id than the node just yielded comes out after it, so the order is
+    /// not sorted. Start ids not present in the subgraph are ignored.
+    ///
+    /// This mirrors Mercurial's lazy DAG-ancestors iterator: a max-heap seeded with `start` grows
+    /// by pushing each not-yet-seen predecessor as nodes are popped, giving an `O((V+E) log V)`
+    /// streaming reachability primitive that callers can stop early.
+    pub fn ancestors(&self, start: &[i32]) -> impl Iterator<Item = i32> {
+        let mut reverse_adjacent: HashMap<i32, Vec<i32>> = HashMap::new();
+        for (&id, handle) in &self.handles {
+            for &adj_id in (*handle.node).borrow().adjacent.iter() {
+                if self.node_set.contains(&adj_id) {
+                    reverse_adjacent.entry(adj_id).or_default().push(id);
+                }
+            }
+        }
+
+        let mut heap = BinaryHeap::new();
+        let mut seen = HashSet::new();
+        for &id in start {
+            if self.node_set.contains(&id) && seen.insert(id) {
+                heap.push(id);
+            }
+        }
+
+        Ancestors { reverse_adjacent, heap, seen }
+    }
+
+    /// Returns a minimum spanning forest (one spanning tree per connected component) of the
+    /// subgraph, treating edges as undirected and using [`NodeHandle::add_weighted_edge`]'s
+    /// weights. Built via Kruskal's algorithm over a [`UnionFind`] keyed on node ids.
+    ///
+    /// Edges to nodes outside the subgraph are ignored. An edge without a recorded weight is
+    /// treated as weight `0`.
+    pub fn minimum_spanning_forest(&self) -> Vec<(i32, i32, i64)> {
+        let mut edges: HashSet<(i32, i32, i64)> = HashSet::new();
+        for (&id, handle) in &self.handles {
+            let node = (*handle.node).borrow();
+            for &adj_id in node.adjacent.iter() {
+                if self.node_set.contains(&adj_id) {
+                    let weight = *node.weights.get(&adj_id).unwrap_or(&0);
+                    let (a, b) = if id <= adj_id { (id, adj_id) } else { (adj_id, id) };
+                    _ = edges.insert((a, b, weight));
+                }
+            }
+        }
+
+        let mut edges: Vec<(i32, i32, i64)> = edges.into_iter().collect();
+        edges.sort_by_key(|&(_, _, weight)| weight);
+
+        let mut union_find = UnionFind::new(self.handles.keys().copied());
+        let mut result = Vec::new();
+        for (a, b, weight) in edges {
+            if union_find.union(a, b) {
+                result.push((a, b, weight));
+            }
+        }
+        result
+    }
+
+    /// Enumerates every simple path from `src` to `dst` within the subgraph. `revisit` decides,
+    /// per node id, whether a node may appear more than once on a path: nodes for which it
+    /// returns `false` may be visited at most once, while nodes for which it returns `true` are
+    /// unrestricted. This generalizes the common "small caves visited once" path-counting
+    /// pattern into flexible route enumeration.
+    pub fn all_paths(&self, src: i32, dst: i32, revisit: impl Fn(i32) -> bool) -> Vec<Vec<i32>> {
+        let mut results = Vec::new();
+        let mut path = Vec::new();
+        let mut visit_count: HashMap<i32, usize> = HashMap::new();
+        self.all_paths_from(src, dst, &revisit, &mut path, &mut visit_count, &mut results);
+        results
+    }
+
+    fn all_paths_from(
+        &self,
+        current: i32,
+        dst: i32,
+        revisit: &impl Fn(i32) -> bool,
+        path: &mut Vec<i32>,
+        visit_count: &mut HashMap<i32, usize>,
+        results: &mut Vec<Vec<i32>>,
+    ) {
+        path.push(current);
+        *visit_count.entry(current).or_insert(0) += 1;
+
+        if current == dst {
+            results.push(path.clone());
+        } else if let Some(handle) = self.handles.get(&current) {
+            let successors: Vec<i32> = (*handle.node)
+                .borrow()
+                .adjacent
+                .iter()
+                .copied()
+                .filter(|adj_id| self.node_set.contains(adj_id))
+                .collect();
+            for successor in successors {
+                let already_visited = visit_count.get(&successor).is_some_and(|&count| count > 0);
+                if already_visited && !revisit(successor) {
+                    continue;
+                }
+                self.all_paths_from(successor, dst, revisit, path, visit_count, results);
+            }
+        }
+
+        *visit_count.get_mut(&current).expect("just incremented above") -= 1;
+        _ = path.pop();
+    }
+}
+
+/// Lazy iterator over the ancestors of a set of starting nodes, returned by
+/// [`SubGraph::ancestors`].
+struct Ancestors {
+    reverse_adjacent: HashMap<i32, Vec<i32>>,
+    heap: BinaryHeap<i32>,
+    seen: HashSet<i32>,
+}
+
+impl Iterator for Ancestors {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        let id = self.heap.pop()?;
+
+        if let Some(predecessors) = self.reverse_adjacent.get(&id) {
+            for &predecessor in predecessors {
+                if self.seen.insert(predecessor) {
+                    self.heap.push(predecessor);
+                }
+            }
+        }
+
+        Some(id)
+    }
+}
+
+/// Disjoint-set (union-find) over node ids, with path compression and union-by-rank.
+struct UnionFind {
+    parent: HashMap<i32, i32>,
+    rank: HashMap<i32, usize>,
+}
+
+impl UnionFind {
+    fn new(ids: impl Iterator<Item = i32>) -> Self {
+        let parent = ids.map(|id| (id, id)).collect();
+        UnionFind { parent, rank: HashMap::new() }
+    }
+
+    fn find(&mut self, id: i32) -> i32 {
+        let root = self.parent[&id];
+        if root == id {
+            return id;
+        }
+        let root = self.find(root);
+        _ = self.parent.insert(id, root);
+        root
+    }
+
+    /// Unions the components of `a` and `b`. Returns `true` iff they were in different
+    /// components (and have now been merged).
+    fn union(&mut self, a: i32, b: i32) -> bool {
+        let (root_a, root_b) = (self.find(a), self.find(b));
+        if root_a == root_b {
+            return false;
+        }
+
+        let (rank_a, rank_b) = (
+            *self.rank.get(&root_a).unwrap_or(&0),
+            *self.rank.get(&root_b).unwrap_or(&0),
+        );
+        if rank_a < rank_b {
+            _ = self.parent.insert(root_a, root_b);
+        } else if rank_a > rank_b {
+            _ = self.parent.insert(root_b, root_a);
+        } else {
+            _ = self.parent.insert(root_b, root_a);
+            _ = self.rank.insert(root_a, rank_a + 1);
+        }
+        true
+    }
 }
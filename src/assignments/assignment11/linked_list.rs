@@ -157,8 +157,8 @@ impl<T: Debug> SinglyLinkedList<T> {
         Self::from_vec(self.into_vec().into_iter().map(f).collect::<Vec<_>>())
     }
 
-    /// Apply given function `f` for each adjacent pair of elements in the vec.
-    /// If `self.length() < 2`, do nothing.
+    /// Apply given function `f` for each adjacent pair of elements in the list, by reference (see
+    /// [`pairwise`](Self::pairwise)). If `self.length() < 2`, returns an empty list.
     ///
     /// # Examples
     ///
@@ -166,24 +166,143 @@ impl<T: Debug> SinglyLinkedList<T> {
     /// // each adjacent pair of elements: `(1, 2)`, `(2, 3)`, `(3, 4)`
     /// // apply `f` to each pair: `f(1, 2) == 3`, `f(2, 3) == 5`, `f(3, 4) == 7`
     /// ==> `[3, 5, 7]`
-    pub fn pair_map<F: Fn(T, T) -> T>(self, f: F) -> Self
-    where
-        T: Clone,
-    {
-        if self.length() < 2 {
-            return self;
+    pub fn pair_map<F: Fn(&T, &T) -> T>(&self, f: F) -> Self {
+        Self::from_vec(
+            self.pairwise()
+                .map(|(current, next)| f(current, next))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Returns a borrowing iterator over the list's elements, front to back, without cloning or
+    /// collecting into a `Vec`.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            current: self.head.as_ref(),
+        }
+    }
+
+    /// Returns a lazy iterator over overlapping windows of `n` consecutive references into the
+    /// list. Yields nothing if `n` is `0` or greater than `self.length()`.
+    pub fn windows(&self, n: usize) -> Windows<'_, T> {
+        Windows {
+            iter: self.iter(),
+            size: n,
+            buffer: Vec::with_capacity(n),
+            done: n == 0,
+        }
+    }
+
+    /// Returns a lazy iterator over `(&T, &T)` for each adjacent pair of elements in the list,
+    /// without cloning.
+    pub fn pairwise(&self) -> Pairwise<'_, T> {
+        Pairwise {
+            iter: self.iter(),
+            previous: None,
         }
+    }
+}
+
+/// Borrowing iterator over a [`SinglyLinkedList`], built by [`SinglyLinkedList::iter`].
+#[derive(Debug)]
+pub struct Iter<'a, T: Debug> {
+    current: Option<&'a Node<T>>,
+}
+
+impl<'a, T: Debug> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.current.take()?;
+        self.current = node.next.as_deref();
+        Some(&node.value)
+    }
+}
+
+/// Owning iterator over a [`SinglyLinkedList`], built by its [`IntoIterator`] impl. Yields `T` by
+/// repeatedly popping from the front, so it never materializes an intermediate `Vec`.
+#[derive(Debug)]
+pub struct IntoIter<T: Debug>(SinglyLinkedList<T>);
+
+impl<T: Debug> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+}
+
+impl<T: Debug> IntoIterator for SinglyLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
 
-        let mut iter = self.into_vec().into_iter();
-        let mut result = Vec::new();
+/// Lazy iterator over overlapping windows of `n` consecutive references into a
+/// [`SinglyLinkedList`], built by [`SinglyLinkedList::windows`].
+#[derive(Debug)]
+pub struct Windows<'a, T: Debug> {
+    iter: Iter<'a, T>,
+    size: usize,
+    buffer: Vec<&'a T>,
+    done: bool,
+}
+
+impl<'a, T: Debug> Iterator for Windows<'a, T> {
+    type Item = Vec<&'a T>;
 
-        let mut current = iter.next().unwrap(); // Safe to unwrap because length >= 2
-        for next in iter {
-            result.push(f(current.clone(), next.clone()));
-            current = next;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
         }
 
-        Self::from_vec(result)
+        if self.buffer.len() < self.size {
+            while self.buffer.len() < self.size {
+                match self.iter.next() {
+                    Some(item) => self.buffer.push(item),
+                    None => {
+                        self.done = true;
+                        return None;
+                    }
+                }
+            }
+        } else {
+            let _ = self.buffer.remove(0);
+            match self.iter.next() {
+                Some(item) => self.buffer.push(item),
+                None => {
+                    self.done = true;
+                    return None;
+                }
+            }
+        }
+
+        Some(self.buffer.clone())
+    }
+}
+
+/// Lazy iterator over adjacent pairs of references into a [`SinglyLinkedList`], built by
+/// [`SinglyLinkedList::pairwise`].
+#[derive(Debug)]
+pub struct Pairwise<'a, T: Debug> {
+    iter: Iter<'a, T>,
+    previous: Option<&'a T>,
+}
+
+impl<'a, T: Debug> Iterator for Pairwise<'a, T> {
+    type Item = (&'a T, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let previous = match self.previous {
+            Some(previous) => previous,
+            None => self.iter.next()?,
+        };
+        let current = self.iter.next()?;
+        self.previous = Some(current);
+        Some((previous, current))
     }
 }
 
@@ -4,29 +4,179 @@
 //!
 //! Refer `mock_storage_grade.rs` for test cases.
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 
+use super::beta_tree::BetaTree;
+
+/// A 64-bit content digest, as computed by [`fnv1a`].
+pub type ContentHash = u64;
+
+/// Hashes `bytes` via FNV-1a, a fast non-cryptographic hash well-suited to content-addressing
+/// (used here the way a backup store like zvault hashes blocks to find duplicates).
+fn fnv1a(bytes: &[u8]) -> ContentHash {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    bytes
+        .iter()
+        .fold(OFFSET_BASIS, |hash, &byte| (hash ^ byte as u64).wrapping_mul(PRIME))
+}
+
+/// Where a stored name's bytes live: charged individually, for names uploaded through the
+/// original content-oblivious [`Storage::upload`], or shared via a content digest, for names
+/// uploaded through [`Storage::upload_content`].
+#[derive(Debug, Clone, Copy)]
+enum Block {
+    /// `size` bytes, charged to capacity on their own regardless of any other file's contents.
+    Sized(usize),
+    /// The digest of a block shared by every name mapped to the same hash.
+    Hashed(ContentHash),
+}
+
+/// How [`MockStorage`] behaves when a file doesn't fit in the remaining capacity.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    /// Fail the upload with the number of bytes short, as before.
+    #[default]
+    Reject,
+    /// Make room by evicting least-recently-used files (by the most recent `upload` or
+    /// `upload_content` that touched them) until the new file fits, like a capacity-bounded
+    /// backup cache. Only fails if the file wouldn't fit even in an empty store.
+    EvictLru,
+}
+
 /// Mock storage.
 #[derive(Debug)]
 pub struct MockStorage {
-    /// Files stored in the storage.
-    ///
-    /// Each entry of the hashmap represents the `(name, size)` of the file.
-    files: RefCell<HashMap<String, usize>>,
+    /// Each stored name's block.
+    names: RefCell<HashMap<String, Block>>,
+
+    /// Each distinct content digest's `(size, refcount)`. A digest is charged against `capacity`
+    /// only once, no matter how many names share it; the refcount tracks how many names currently
+    /// do, so the block can be reclaimed once the last of them is overwritten or removed.
+    blocks: RefCell<HashMap<ContentHash, (usize, usize)>>,
 
     /// Capacity of the storage.
     ///
     /// The total size of files stored on the storage cannot exceed the capacity.
     capacity: usize,
+
+    /// How to behave when a file doesn't fit.
+    policy: Policy,
+
+    /// Monotonically increasing counter, stamped onto a name's entry in `last_access` every time
+    /// it's uploaded, so [`Policy::EvictLru`] can tell which name was touched longest ago.
+    access_counter: Cell<u64>,
+
+    /// Each currently-stored name's stamp from `access_counter` as of its last upload.
+    last_access: RefCell<HashMap<String, u64>>,
+
+    /// Names evicted so far under [`Policy::EvictLru`], oldest first.
+    evicted: RefCell<Vec<String>>,
 }
 
 impl MockStorage {
-    /// Creates a new mock storage.
+    /// Creates a new mock storage with [`Policy::Reject`].
     pub fn new(capacity: usize) -> Self {
+        Self::with_policy(capacity, Policy::default())
+    }
+
+    /// Creates a new mock storage with the given eviction policy.
+    pub fn with_policy(capacity: usize, policy: Policy) -> Self {
         Self {
-            files: RefCell::new(HashMap::new()),
+            names: RefCell::new(HashMap::new()),
+            blocks: RefCell::new(HashMap::new()),
             capacity,
+            policy,
+            access_counter: Cell::new(0),
+            last_access: RefCell::new(HashMap::new()),
+            evicted: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns the names evicted so far under [`Policy::EvictLru`], oldest first.
+    pub fn evicted_names(&self) -> Vec<String> {
+        self.evicted.borrow().clone()
+    }
+
+    /// Uploads many `(name, size)` pairs at once, batching them through a [`BetaTree`] first so
+    /// repeated names within the same batch (last write wins, as consecutive calls to `upload`
+    /// would behave) are resolved in amortized time rather than re-scanning `self.used()` once per
+    /// duplicate. Each resolved entry is then applied through the ordinary [`Storage::upload`]
+    /// path, so capacity is still checked and enforced per file.
+    pub fn bulk_upload(&self, files: impl IntoIterator<Item = (String, usize)>) -> Result<(), usize> {
+        let mut index = BetaTree::default();
+        for (name, size) in files {
+            index.insert(&name, size);
+        }
+
+        for name in index.keys() {
+            let size = index.get(&name).expect("name was just collected from the index");
+            self.upload(&name, size)?;
+        }
+        Ok(())
+    }
+
+    /// Releases whatever block `name` currently occupies, if any: decrements its digest's
+    /// refcount and reclaims the block entirely once nothing references it. A no-op if `name`
+    /// isn't stored.
+    fn release(&self, name: &str) {
+        let released = self.names.borrow_mut().remove(name);
+        if let Some(Block::Hashed(hash)) = released {
+            let mut blocks = self.blocks.borrow_mut();
+            let now_empty = match blocks.get_mut(&hash) {
+                Some((_, refcount)) => {
+                    *refcount -= 1;
+                    *refcount == 0
+                }
+                None => false,
+            };
+            if now_empty {
+                let _ = blocks.remove(&hash);
+            }
+        }
+    }
+
+    /// Stamps `name` with a fresh, strictly increasing access timestamp.
+    fn touch(&self, name: &str) {
+        let stamp = self.access_counter.get();
+        self.access_counter.set(stamp + 1);
+        let _ = self.last_access.borrow_mut().insert(name.to_string(), stamp);
+    }
+
+    /// Ensures `space_required` bytes are available beyond `capacity + allowance` (the extra
+    /// `allowance` accounts for space an in-flight upload is about to free on its own), evicting
+    /// least-recently-used names other than `protect` under [`Policy::EvictLru`] until it fits.
+    /// Fails with the number of bytes still short if the policy is [`Policy::Reject`], or if even
+    /// evicting everything else wouldn't make enough room.
+    fn make_room(&self, space_required: usize, allowance: usize, protect: &str) -> Result<(), usize> {
+        loop {
+            let current_used = self.used();
+            if current_used + space_required <= self.capacity + allowance {
+                return Ok(());
+            }
+            if self.policy == Policy::Reject {
+                return Err(current_used + space_required - self.capacity - allowance);
+            }
+
+            let victim = self
+                .last_access
+                .borrow()
+                .iter()
+                .filter(|(name, _)| name.as_str() != protect)
+                .min_by_key(|(_, &stamp)| stamp)
+                .map(|(name, _)| name.clone());
+
+            match victim {
+                Some(name) => {
+                    self.release(&name);
+                    let _ = self.last_access.borrow_mut().remove(&name);
+                    self.evicted.borrow_mut().push(name);
+                }
+                None => {
+                    return Err(current_used + space_required - self.capacity - allowance);
+                }
+            }
         }
     }
 }
@@ -38,33 +188,125 @@ pub trait Storage {
     /// Returns `Err` with insufficient memory size if there is no free space to upload a file.
     fn upload(&self, name: &str, size: usize) -> Result<(), usize>;
 
-    /// Returns the used memory size of the storage.
+    /// Uploads a file by its content rather than a bare size, deduplicating by a content hash: if
+    /// `content` is identical to some other stored file's, the underlying block is shared and
+    /// `capacity` is only charged once between them. If a file with the same name already exists,
+    /// overwrite it. Returns `Err` with insufficient memory size if there is no free space for a
+    /// previously-unseen block.
+    fn upload_content(&self, name: &str, content: &[u8]) -> Result<(), usize>;
+
+    /// Removes a stored name, if present, returning whether a file was actually removed.
+    fn remove(&self, name: &str) -> bool;
+
+    /// Returns the used memory size of the storage: physical bytes actually charged against
+    /// `capacity`, with identical content uploaded under multiple names (via
+    /// [`upload_content`](Self::upload_content)) counted once. See [`logical_used`](Self::logical_used)
+    /// for the un-deduplicated total.
     fn used(&self) -> usize;
 
+    /// Returns the sum of every stored file's size, counting content shared across names once per
+    /// name rather than once per distinct block (unlike [`used`](Self::used)).
+    fn logical_used(&self) -> usize;
+
     /// Returns the capacity of the storage.
     fn capacity(&self) -> usize;
 }
 
 impl Storage for MockStorage {
     fn upload(&self, name: &str, size: usize) -> Result<(), usize> {
-        let mut files = self.files.borrow_mut();
-        let current_used: usize = files.values().sum();
-        let space_required = if files.contains_key(name) {
-            size.saturating_sub(*files.get(name).unwrap_or(&0))
-        } else {
-            size
+        // If `name` currently owns a block, overwriting it will free that block's space in the
+        // same operation -- the full size for a `Block::Sized`, or the underlying block's size
+        // for a `Block::Hashed` whose only remaining reference is `name`'s.
+        let freed = match self.names.borrow().get(name) {
+            Some(Block::Sized(previous_size)) => *previous_size,
+            Some(Block::Hashed(old_hash)) => self
+                .blocks
+                .borrow()
+                .get(old_hash)
+                .filter(|(_, refcount)| *refcount == 1)
+                .map(|(size, _)| *size)
+                .unwrap_or(0),
+            None => 0,
         };
+        // `self.used()` inside `make_room` is computed before `self.release(name)` below runs, so
+        // it still includes `freed`'s bytes -- crediting `freed` again as an allowance on top of
+        // that would double-count it. `space_required` already nets `freed` out, so `make_room`
+        // only needs to fit the (possibly smaller) remainder against the unmodified `used()`.
+        let space_required = size.saturating_sub(freed);
+        self.make_room(space_required, 0, name)?;
 
-        if current_used + space_required > self.capacity {
-            Err(space_required - (self.capacity - current_used))
-        } else {
-            let _ = files.insert(name.to_string(), size);
-            Ok(())
-        }
+        self.release(name);
+        let _ = self.names.borrow_mut().insert(name.to_string(), Block::Sized(size));
+        self.touch(name);
+        Ok(())
+    }
+
+    fn upload_content(&self, name: &str, content: &[u8]) -> Result<(), usize> {
+        let hash = fnv1a(content);
+
+        // If `name` currently owns the sole reference to a different block, overwriting it will
+        // free that block's space in the same operation.
+        let freed = match self.names.borrow().get(name) {
+            Some(Block::Hashed(old_hash)) if *old_hash != hash => self
+                .blocks
+                .borrow()
+                .get(old_hash)
+                .filter(|(_, refcount)| *refcount == 1)
+                .map(|(size, _)| *size)
+                .unwrap_or(0),
+            _ => 0,
+        };
+
+        // See the analogous comment in `upload`: `freed` belongs in `space_required`, netted out
+        // the same way, not passed as `make_room`'s allowance (which would double-credit it since
+        // `used()` inside `make_room` still includes `freed`'s bytes at this point).
+        let already_known = self.blocks.borrow().contains_key(&hash);
+        let space_required = if already_known { 0 } else { content.len().saturating_sub(freed) };
+        self.make_room(space_required, 0, name)?;
+
+        self.release(name);
+        let _ = self
+            .blocks
+            .borrow_mut()
+            .entry(hash)
+            .and_modify(|(_, refcount)| *refcount += 1)
+            .or_insert((content.len(), 1));
+        let _ = self.names.borrow_mut().insert(name.to_string(), Block::Hashed(hash));
+        self.touch(name);
+        Ok(())
+    }
+
+    fn remove(&self, name: &str) -> bool {
+        let existed = self.names.borrow().contains_key(name);
+        self.release(name);
+        let _ = self.last_access.borrow_mut().remove(name);
+        existed
     }
 
     fn used(&self) -> usize {
-        self.files.borrow().values().sum()
+        let sized: usize = self
+            .names
+            .borrow()
+            .values()
+            .filter_map(|block| match block {
+                Block::Sized(size) => Some(*size),
+                Block::Hashed(_) => None,
+            })
+            .sum();
+        let shared: usize = self.blocks.borrow().values().map(|(size, _)| *size).sum();
+        sized + shared
+    }
+
+    fn logical_used(&self) -> usize {
+        let blocks = self.blocks.borrow();
+        self.names
+            .borrow()
+            .values()
+            .map(|block| match block {
+                Block::Sized(size) => *size,
+                Block::Hashed(hash) => blocks.get(hash).map(|(size, _)| *size).unwrap_or(0),
+            })
+            .sum()
     }
 
     fn capacity(&self) -> usize {
@@ -90,6 +332,11 @@ impl<'a, T: Storage> FileUploader<'a, T> {
     pub fn upload(&self, name: &str, size: usize) -> Result<(), usize> {
         self.storage.upload(name, size)
     }
+
+    /// Uploads a file to the internal storage by its content.
+    pub fn upload_content(&self, name: &str, content: &[u8]) -> Result<(), usize> {
+        self.storage.upload_content(name, content)
+    }
 }
 
 /// Storage usage analyzer.
@@ -109,4 +356,16 @@ impl<'a, T: Storage> UsageAnalyzer<'a, T> {
     pub fn is_usage_under_bound(&self) -> bool {
         (self.storage.used() as f64 / self.storage.capacity() as f64) < self.bound
     }
+
+    /// Returns the deduplication ratio: logical bytes stored per physical byte actually charged
+    /// against capacity. `1.0` means no content is shared; higher values mean more sharing.
+    /// Returns `1.0` when nothing has been stored, rather than dividing by zero.
+    pub fn dedup_ratio(&self) -> f64 {
+        let used = self.storage.used();
+        if used == 0 {
+            1.0
+        } else {
+            self.storage.logical_used() as f64 / used as f64
+        }
+    }
 }